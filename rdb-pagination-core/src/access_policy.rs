@@ -0,0 +1,110 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// What `OrderByOptions::to_sql_with` does with an enabled option which requires a capability the caller does not have.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DeniedOptionAction {
+    /// Ignore the option, as if its `OrderMethod` is disabled.
+    #[default]
+    Drop,
+    /// Return `AccessDeniedError`.
+    Reject,
+}
+
+/// A permission checker supplied by the caller of `OrderByOptions::to_sql_with`, which tells whether the caller has a capability.
+///
+/// ```rust
+/// # use rdb_pagination_core::AccessPolicy;
+/// #
+/// let is_admin = false;
+///
+/// let policy = AccessPolicy::reject(|capability: &str| {
+///     capability == "admin" && is_admin
+/// });
+///
+/// assert!(policy.check("salary", "admin", false).is_ok());
+/// assert!(policy.check("salary", "admin", true).is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AccessPolicy<F> {
+    checker: F,
+    action:  DeniedOptionAction,
+}
+
+impl<F: Fn(&str) -> bool> AccessPolicy<F> {
+    /// Create a policy.
+    #[inline]
+    pub const fn new(checker: F, action: DeniedOptionAction) -> Self {
+        Self {
+            checker,
+            action,
+        }
+    }
+
+    /// Create a policy which drops options requiring capabilities the caller does not have.
+    #[inline]
+    pub const fn drop(checker: F) -> Self {
+        Self::new(checker, DeniedOptionAction::Drop)
+    }
+
+    /// Create a policy which rejects enabled options requiring capabilities the caller does not have.
+    #[inline]
+    pub const fn reject(checker: F) -> Self {
+        Self::new(checker, DeniedOptionAction::Reject)
+    }
+
+    #[inline]
+    pub const fn get_action(&self) -> DeniedOptionAction {
+        self.action
+    }
+
+    /// Whether the caller has the capability.
+    #[inline]
+    pub fn is_allowed(&self, capability: &str) -> bool {
+        (self.checker)(capability)
+    }
+
+    /// Check an option of the `field` which requires the `capability`. `enabled` is whether its `OrderMethod` is enabled.
+    ///
+    /// Return `Ok(true)` if the option can be applied, or `Ok(false)` if it should be dropped.
+    #[inline]
+    pub fn check(
+        &self,
+        field: &'static str,
+        capability: &'static str,
+        enabled: bool,
+    ) -> Result<bool, AccessDeniedError> {
+        if self.is_allowed(capability) {
+            Ok(true)
+        } else if enabled && self.action == DeniedOptionAction::Reject {
+            Err(AccessDeniedError {
+                field,
+                capability,
+            })
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+/// Error returned by `OrderByOptions::to_sql_with` when an enabled option requires a capability the caller does not have.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AccessDeniedError {
+    pub field:      &'static str,
+    pub capability: &'static str,
+}
+
+impl Display for AccessDeniedError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ordering by `{}` requires the `{}` capability",
+            self.field, self.capability
+        ))
+    }
+}
+
+impl Error for AccessDeniedError {}
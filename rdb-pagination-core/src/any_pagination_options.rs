@@ -0,0 +1,270 @@
+use crate::{
+    OffsetError, OffsetPaginationOptions, OrderByOptions, PageOutOfRangeError, PageQuery,
+    Pagination, PaginationOptions,
+};
+
+/// Either page-based `PaginationOptions` or `OffsetPaginationOptions`, for endpoints which accept both.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rdb_pagination_core::{AnyPaginationOptions, PaginationOptions};
+/// #
+/// let options = AnyPaginationOptions::from(
+///     PaginationOptions::new().page(2).items_per_page(20),
+/// );
+///
+/// assert_eq!(2, options.to_pagination(50).get_page());
+///
+/// let options = AnyPaginationOptions::from(
+///     PaginationOptions::new().items_per_page(20).at_offset(35),
+/// );
+///
+/// assert_eq!(35, options.to_pagination(50).get_offset());
+/// ```
+///
+/// With the `serde` feature, this type implements each serde trait when `T` implements the same trait. It is either in the `{ "page", "items_per_page", "order_by" }` shape, or in the `{ "offset", "limit", "order_by" }` shape if `offset` or `limit` is present. With `offset`, `items_per_page` becomes the limit, as `PaginationOptions::at_offset` does. Every field is optional when deserializing, but `page` cannot be used with `offset` or `limit`, `items_per_page` cannot be used with `limit`, and `offset` cannot be bigger than `PageQuery::MAX_OFFSET`. `order_by` is checked by `OrderByOptions::validate`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "AnyPaginationOptionsInput<T>"))]
+pub enum AnyPaginationOptions<T: OrderByOptions = ()> {
+    /// Options with a page number.
+    Page(PaginationOptions<T>),
+    /// Options with an offset and a limit.
+    Offset(OffsetPaginationOptions<T>),
+}
+
+#[cfg(feature = "serde")]
+impl<T: OrderByOptions + serde::Serialize> serde::Serialize for AnyPaginationOptions<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Page(options) => options.serialize(serializer),
+            Self::Offset(options) => options.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct AnyPaginationOptionsInput<T> {
+    #[serde(default)]
+    page:           Option<usize>,
+    #[serde(default)]
+    items_per_page: Option<usize>,
+    #[serde(default)]
+    offset:         Option<u64>,
+    #[serde(default)]
+    limit:          Option<usize>,
+    #[serde(default)]
+    order_by:       T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: OrderByOptions> TryFrom<AnyPaginationOptionsInput<T>> for AnyPaginationOptions<T> {
+    type Error = std::borrow::Cow<'static, str>;
+
+    #[inline]
+    fn try_from(value: AnyPaginationOptionsInput<T>) -> Result<Self, Self::Error> {
+        if value.page.is_some() && (value.offset.is_some() || value.limit.is_some()) {
+            return Err("page cannot be used with offset or limit".into());
+        }
+
+        if value.items_per_page.is_some() && value.limit.is_some() {
+            return Err("items_per_page cannot be used with limit".into());
+        }
+
+        if let Some(offset) = value.offset
+            && offset > PageQuery::MAX_OFFSET
+        {
+            return Err(format!("offset cannot be bigger than {}", PageQuery::MAX_OFFSET).into());
+        }
+
+        if let Err(error) = value.order_by.validate() {
+            return Err(error.to_string().into());
+        }
+
+        if value.offset.is_some() || value.limit.is_some() {
+            // `items_per_page` cannot be used with `limit`, and `0` means unlimited
+            let limit = match value.items_per_page {
+                Some(0) | None => value.limit,
+                items_per_page => items_per_page,
+            };
+
+            Ok(Self::Offset(OffsetPaginationOptions {
+                offset: value.offset.unwrap_or(0),
+                limit,
+                order_by: value.order_by,
+            }))
+        } else {
+            Ok(Self::Page(PaginationOptions {
+                page:           value.page.unwrap_or(1),
+                items_per_page: value.items_per_page.unwrap_or(0),
+                order_by:       value.order_by,
+            }))
+        }
+    }
+}
+
+impl<T: OrderByOptions> Default for AnyPaginationOptions<T> {
+    /// Create the default page-based options.
+    #[inline]
+    fn default() -> Self {
+        Self::Page(PaginationOptions::default())
+    }
+}
+
+impl<T: OrderByOptions> From<PaginationOptions<T>> for AnyPaginationOptions<T> {
+    #[inline]
+    fn from(options: PaginationOptions<T>) -> Self {
+        Self::Page(options)
+    }
+}
+
+impl<T: OrderByOptions> From<OffsetPaginationOptions<T>> for AnyPaginationOptions<T> {
+    #[inline]
+    fn from(options: OffsetPaginationOptions<T>) -> Self {
+        Self::Offset(options)
+    }
+}
+
+impl<T: OrderByOptions> AnyPaginationOptions<T> {
+    /// Get the ordering options.
+    #[inline]
+    pub const fn get_order_by(&self) -> &T {
+        match self {
+            Self::Page(options) => &options.order_by,
+            Self::Offset(options) => &options.order_by,
+        }
+    }
+
+    /// See `PaginationOptions::try_offset_with_max_page` and `OffsetPaginationOptions::try_offset_with_max_page`.
+    #[inline]
+    pub const fn try_offset_with_max_page(&self, max_page: usize) -> Result<u64, OffsetError> {
+        match self {
+            Self::Page(options) => options.try_offset_with_max_page(max_page),
+            Self::Offset(options) => options.try_offset_with_max_page(max_page),
+        }
+    }
+
+    #[cfg(any(feature = "mysql", feature = "sqlite", feature = "mssql", feature = "mssql2008"))]
+    #[inline]
+    pub(crate) const fn to_page_query(&self) -> PageQuery {
+        match self {
+            Self::Page(options) => options.to_page_query(),
+            Self::Offset(options) => options.to_page_query(),
+        }
+    }
+
+    /// See `PaginationOptions::to_pagination` and `OffsetPaginationOptions::to_pagination`.
+    #[inline]
+    pub const fn to_pagination(&self, total_items: usize) -> Pagination {
+        match self {
+            Self::Page(options) => options.to_pagination(total_items),
+            Self::Offset(options) => options.to_pagination(total_items),
+        }
+    }
+
+    /// See `PaginationOptions::try_to_pagination` and `OffsetPaginationOptions::try_to_pagination`.
+    #[inline]
+    pub const fn try_to_pagination(
+        &self,
+        total_items: usize,
+    ) -> Result<Pagination, PageOutOfRangeError> {
+        match self {
+            Self::Page(options) => options.try_to_pagination(total_items),
+            Self::Offset(options) => options.try_to_pagination(total_items),
+        }
+    }
+
+    /// See `PaginationOptions::is_out_of_range` and `OffsetPaginationOptions::is_out_of_range`.
+    #[inline]
+    pub const fn is_out_of_range(&self, total_items: usize) -> bool {
+        match self {
+            Self::Page(options) => options.is_out_of_range(total_items),
+            Self::Offset(options) => options.is_out_of_range(total_items),
+        }
+    }
+
+    /// See `PaginationOptions::page_query` and `OffsetPaginationOptions::page_query`.
+    #[inline]
+    pub const fn page_query(&self, total_items: usize) -> PageQuery {
+        match self {
+            Self::Page(options) => options.page_query(total_items),
+            Self::Offset(options) => options.page_query(total_items),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T: OrderByOptions> AnyPaginationOptions<T> {
+    /// Generate a `LIMIT` with `OFFSET` clause for MySQL. See `PageQuery::to_mysql_limit_offset`.
+    #[inline]
+    pub fn to_mysql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.to_page_query().to_mysql_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for MySQL which checks the row number generated by `SqlOrderByComponent::format_mysql_row_number`. See `PageQuery::to_mysql_row_number_filter`.
+    #[inline]
+    pub fn to_mysql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mysql_row_number_filter(row_number_column_name, s)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T: OrderByOptions> AnyPaginationOptions<T> {
+    /// Generate a `LIMIT` with `OFFSET` clause for SQLite. See `PageQuery::to_sqlite_limit_offset`.
+    #[inline]
+    pub fn to_sqlite_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.to_page_query().to_sqlite_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for SQLite which checks the row number generated by `SqlOrderByComponent::format_sqlite_row_number`. See `PageQuery::to_sqlite_row_number_filter`.
+    #[inline]
+    pub fn to_sqlite_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_sqlite_row_number_filter(row_number_column_name, s)
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl<T: OrderByOptions> AnyPaginationOptions<T> {
+    /// Generate a `OFFSET` with `FETCH` clause for Microsoft SQL Server. See `PageQuery::to_mssql_limit_offset`.
+    #[inline]
+    pub fn to_mssql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.to_page_query().to_mssql_limit_offset(s)
+    }
+}
+
+#[cfg(any(feature = "mssql", feature = "mssql2008"))]
+impl<T: OrderByOptions> AnyPaginationOptions<T> {
+    /// Generate a `WHERE` clause for Microsoft SQL Server which checks the row number generated by `SqlOrderByComponent::format_mssql_row_number`. See `PageQuery::to_mssql_row_number_filter`.
+    #[inline]
+    pub fn to_mssql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mssql_row_number_filter(row_number_column_name, s)
+    }
+}
+
+#[cfg(feature = "mssql2008")]
+impl<T: OrderByOptions> AnyPaginationOptions<T> {
+    /// Generate a `WHERE` clause for Microsoft SQL Server 2008 and earlier (used for check the row number). See `PageQuery::to_mssql2008_limit_offset`.
+    #[inline]
+    pub fn to_mssql2008_limit_offset<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mssql2008_limit_offset(row_number_column_name, s)
+    }
+}
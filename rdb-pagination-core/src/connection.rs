@@ -0,0 +1,82 @@
+use async_graphql::{
+    OutputType, SimpleObject,
+    connection::{Connection, CursorType, Edge},
+};
+
+use crate::{PageQuery, Pagination};
+
+/// Additional fields of a connection created by this crate.
+#[derive(Debug, Clone, Copy, SimpleObject)]
+pub struct ConnectionFields {
+    /// Total number of items.
+    pub total_count: usize,
+}
+
+/// Create a Relay connection from edges with any cursor type, e.g. keyset cursors.
+///
+/// `startCursor` and `endCursor` of `pageInfo` come from the first and the last edges.
+pub fn new_connection<C: CursorType + Send + Sync, N: OutputType>(
+    has_previous_page: bool,
+    has_next_page: bool,
+    total_count: usize,
+    edges: impl IntoIterator<Item = (C, N)>,
+) -> Connection<C, N, ConnectionFields> {
+    let mut connection =
+        Connection::with_additional_fields(has_previous_page, has_next_page, ConnectionFields {
+            total_count,
+        });
+
+    connection.edges.extend(edges.into_iter().map(|(cursor, node)| Edge::new(cursor, node)));
+
+    connection
+}
+
+impl Pagination {
+    /// Create a Relay connection for the nodes on the current page. The cursor of each node is its offset in the whole result set.
+    #[inline]
+    pub fn to_connection<N: OutputType>(
+        &self,
+        nodes: impl IntoIterator<Item = N>,
+    ) -> Connection<usize, N, ConnectionFields> {
+        let nodes = nodes.into_iter().collect::<Vec<N>>();
+        let count = nodes.len();
+
+        let offset = usize::try_from(self.get_offset()).unwrap_or(usize::MAX);
+
+        new_connection(
+            offset > 0,
+            offset.saturating_add(count) < self.get_total_items(),
+            self.get_total_items(),
+            nodes.into_iter().enumerate().map(|(i, node)| (offset.saturating_add(i), node)),
+        )
+    }
+}
+
+impl PageQuery {
+    /// Create a Relay connection for the nodes fetched with this `PageQuery`. The cursor of each node is its offset in the whole result set.
+    ///
+    /// If the `PageQuery` is reversed, `nodes` should have been reversed again in memory.
+    pub fn to_connection<N: OutputType>(
+        &self,
+        total_items: usize,
+        nodes: impl IntoIterator<Item = N>,
+    ) -> Connection<usize, N, ConnectionFields> {
+        let nodes = nodes.into_iter().collect::<Vec<N>>();
+        let count = nodes.len();
+
+        let offset = usize::try_from(self.offset).unwrap_or(usize::MAX);
+
+        let start = if self.reversed {
+            total_items.saturating_sub(offset.saturating_add(count))
+        } else {
+            offset
+        };
+
+        new_connection(
+            start > 0,
+            start.saturating_add(count) < total_items,
+            total_items,
+            nodes.into_iter().enumerate().map(|(i, node)| (start.saturating_add(i), node)),
+        )
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter, Write},
+};
+
+use crate::{
+    AnyPaginationOptions, OffsetPaginationOptions, OrderByField, OrderByFields, OrderType,
+    PageQuery, Pagination, PaginationOptions,
+};
+
+/// The `source` member of a JSON:API error object.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JsonApiErrorSource {
+    /// The query parameter which caused the error, e.g. `page[size]`.
+    pub parameter: String,
+}
+
+/// A JSON:API error object.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JsonApiError {
+    /// The HTTP status code, i.e. `"400"`.
+    pub status: String,
+    pub title:  String,
+    pub detail: String,
+    pub source: JsonApiErrorSource,
+}
+
+impl JsonApiError {
+    #[inline]
+    fn bad_request(parameter: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            status: String::from("400"),
+            title:  String::from("Invalid Query Parameter"),
+            detail: detail.into(),
+            source: JsonApiErrorSource {
+                parameter: parameter.into()
+            },
+        }
+    }
+}
+
+impl Display for JsonApiError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{}: {}", self.source.parameter, self.detail))
+    }
+}
+
+impl Error for JsonApiError {}
+
+/// A JSON:API document which only has the top-level `errors` member.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JsonApiErrors {
+    pub errors: Vec<JsonApiError>,
+}
+
+impl Display for JsonApiErrors {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+
+            Display::fmt(error, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for JsonApiErrors {}
+
+/// The top-level `links` member of a JSON:API document.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JsonApiLinks {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub first:     String,
+    /// `None` (serialized as `null`) on the first page.
+    pub prev:      Option<String>,
+    /// `None` (serialized as `null`) on the last page.
+    pub next:      Option<String>,
+    pub last:      String,
+}
+
+/// The `page` member of `JsonApiMeta`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonApiPageMeta {
+    pub number:      usize,
+    pub size:        usize,
+    pub total_pages: usize,
+    pub total_items: usize,
+    /// Only present if the `Pagination` is offset-based.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset:      Option<u64>,
+}
+
+/// The top-level `meta` member of a JSON:API document.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JsonApiMeta {
+    pub page: JsonApiPageMeta,
+}
+
+fn parse_usize(parameter: &str, value: &str, errors: &mut Vec<JsonApiError>) -> Option<usize> {
+    match value.parse::<usize>() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            errors.push(JsonApiError::bad_request(
+                parameter,
+                format!("`{value}` is not a non-negative integer"),
+            ));
+
+            None
+        },
+    }
+}
+
+impl<T: OrderByFields> AnyPaginationOptions<T> {
+    /// Create pagination options from the query parameters of a JSON:API request. Keys and values should have been percent-decoded.
+    ///
+    /// * `page[number]` and `page[size]` set the page number and the number of items per page of `PaginationOptions`.
+    /// * `page[offset]` (or `page[cursor]`, whose value is the offset of the first item, like the cursors of `PageInfo`) and `page[limit]` set the offset and the limit of `OffsetPaginationOptions`.
+    /// * `sort` is a comma-separated list of field names (see `OrderByField::as_str`), each of which may be prefixed with `-` to sort in descending order. If it is absent, `T::default()` is used. The ordering options are checked by `OrderByOptions::validate`.
+    ///
+    /// Other parameters are ignored. All invalid parameters are reported, each with its name in `source.parameter`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{AnyPaginationOptions, PaginationOptions};
+    /// #
+    /// let options = <AnyPaginationOptions>::from_json_api_parameters([
+    ///     ("page[number]", "3"),
+    ///     ("page[size]", "20"),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     options,
+    ///     AnyPaginationOptions::Page(PaginationOptions {
+    ///         page: 3,
+    ///         items_per_page: 20,
+    ///         ..
+    ///     })
+    /// ));
+    /// ```
+    pub fn from_json_api_parameters<K: AsRef<str>, V: AsRef<str>>(
+        parameters: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, JsonApiErrors> {
+        let mut errors = Vec::new();
+
+        let mut number = None;
+        let mut size = None;
+        let mut offset = None;
+        let mut limit = None;
+        let mut order_by = None;
+
+        for (key, value) in parameters {
+            let (key, value) = (key.as_ref(), value.as_ref());
+
+            let (key, slot): (&'static str, _) = match key {
+                "page[number]" => ("page[number]", &mut number),
+                "page[size]" => ("page[size]", &mut size),
+                "page[offset]" => ("page[offset]", &mut offset),
+                "page[cursor]" => ("page[cursor]", &mut offset),
+                "page[limit]" => ("page[limit]", &mut limit),
+                "sort" => {
+                    order_by = Some(Self::parse_json_api_sort(value, &mut errors));
+
+                    continue;
+                },
+                _ => continue,
+            };
+
+            if let Some(value) = parse_usize(key, value, &mut errors) {
+                *slot = Some((key, value));
+            }
+        }
+
+        let order_by: T = order_by.unwrap_or_default();
+
+        if let Err(error) = order_by.validate() {
+            errors.push(JsonApiError::bad_request("sort", error.to_string()));
+        }
+
+        let options = if offset.is_some() || limit.is_some() {
+            if let Some((key, _)) = number.or(size) {
+                errors.push(JsonApiError::bad_request(
+                    key,
+                    "cannot be used with `page[offset]`, `page[cursor]` or `page[limit]`",
+                ));
+            }
+
+            if let Some((key, offset)) = offset
+                && offset as u64 > PageQuery::MAX_OFFSET
+            {
+                errors.push(JsonApiError::bad_request(
+                    key,
+                    format!("cannot be bigger than {}", PageQuery::MAX_OFFSET),
+                ));
+            }
+
+            Self::Offset(OffsetPaginationOptions {
+                offset: offset.map_or(0, |(_, offset)| offset as u64),
+                limit: limit.map(|(_, limit)| limit),
+                order_by,
+            })
+        } else {
+            Self::Page(PaginationOptions {
+                page: number.map_or(1, |(_, number)| number),
+                items_per_page: size.map_or(0, |(_, size)| size),
+                order_by,
+            })
+        };
+
+        if errors.is_empty() {
+            Ok(options)
+        } else {
+            Err(JsonApiErrors {
+                errors,
+            })
+        }
+    }
+
+    fn parse_json_api_sort(value: &str, errors: &mut Vec<JsonApiError>) -> T {
+        let mut fields = Vec::new();
+
+        for name in value.split(',') {
+            let (name, order_type) = match name.strip_prefix('-') {
+                Some(name) => (name, OrderType::Desc),
+                None => (name, OrderType::Asc),
+            };
+
+            match T::Field::from_name(name) {
+                Some(field) => fields.push((field, order_type)),
+                None => errors.push(JsonApiError::bad_request(
+                    "sort",
+                    format!("`{name}` is not a sortable field"),
+                )),
+            }
+        }
+
+        T::from_fields(&fields)
+    }
+}
+
+impl Pagination {
+    /// Create the top-level `links` member of a JSON:API document.
+    ///
+    /// `base_url` should include other query parameters, such as `sort`, but not the `page` parameters. The `page[number]` and `page[size]` parameters (percent-encoded) are appended to it, or the `page[offset]` and `page[limit]` parameters if the `Pagination` is offset-based. `page[limit]` is left out if `items_per_page` is `0`, so that the links stay unlimited.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::Pagination;
+    /// #
+    /// let pagination =
+    ///     Pagination::new().items_per_page(20).total_items(50).page(3);
+    ///
+    /// let links = pagination.to_json_api_links("/users?sort=-name");
+    ///
+    /// assert_eq!(
+    ///     "/users?sort=-name&page%5Bnumber%5D=2&page%5Bsize%5D=20",
+    ///     links.prev.unwrap()
+    /// );
+    /// assert_eq!(None, links.next);
+    /// ```
+    pub fn to_json_api_links(&self, base_url: &str) -> JsonApiLinks {
+        let items_per_page = self.get_items_per_page();
+
+        let link = |page: usize| {
+            let mut url = String::from(base_url);
+
+            url.push(if base_url.contains('?') { '&' } else { '?' });
+
+            url.write_fmt(format_args!("page%5Bnumber%5D={page}&page%5Bsize%5D={items_per_page}"))
+                .unwrap();
+
+            url
+        };
+
+        let page = self.get_page();
+        let total_pages = self.get_total_pages().max(1);
+
+        if self.is_offset_based() {
+            let link = |offset: u64| {
+                let mut url = String::from(base_url);
+
+                url.push(if base_url.contains('?') { '&' } else { '?' });
+
+                url.write_fmt(format_args!("page%5Boffset%5D={offset}")).unwrap();
+
+                // `0` means unlimited, which `page[limit]` cannot express
+                if items_per_page > 0 {
+                    url.write_fmt(format_args!("&page%5Blimit%5D={items_per_page}")).unwrap();
+                }
+
+                url
+            };
+
+            let offset = self.get_offset();
+            let next_offset = offset.saturating_add(items_per_page as u64);
+
+            return JsonApiLinks {
+                self_link: link(offset),
+                first:     link(0),
+                prev:      if offset > 0 {
+                    Some(link(match items_per_page {
+                        0 => 0,
+                        _ => offset.saturating_sub(items_per_page as u64),
+                    }))
+                } else {
+                    None
+                },
+                next:      if items_per_page > 0 && next_offset < self.get_total_items() as u64 {
+                    Some(link(next_offset))
+                } else {
+                    None
+                },
+                last:      link((items_per_page * (total_pages - 1)) as u64),
+            };
+        }
+
+        JsonApiLinks {
+            self_link: link(page),
+            first:     link(1),
+            prev:      if page > 1 { Some(link(page - 1)) } else { None },
+            next:      if page < total_pages { Some(link(page + 1)) } else { None },
+            last:      link(total_pages),
+        }
+    }
+
+    /// Create the top-level `meta` member of a JSON:API document.
+    #[inline]
+    pub fn to_json_api_meta(&self) -> JsonApiMeta {
+        JsonApiMeta {
+            page: JsonApiPageMeta {
+                number:      self.get_page(),
+                size:        self.get_items_per_page(),
+                total_pages: self.get_total_pages(),
+                total_items: self.get_total_items(),
+                offset:      if self.is_offset_based() { Some(self.get_offset()) } else { None },
+            },
+        }
+    }
+}
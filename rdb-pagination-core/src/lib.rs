@@ -6,22 +6,63 @@ SQL query extension library for handling pagination and sorting in relational da
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod access_policy;
+mod any_pagination_options;
+#[cfg(feature = "async-graphql")]
+mod connection;
+#[cfg(feature = "json-api")]
+mod json_api;
+#[cfg(feature = "odata")]
+mod odata;
+mod offset_error;
+mod offset_pagination_options;
 mod order_builder;
+mod order_by_field;
 mod order_by_options;
 mod order_method;
+mod order_method_limits;
 mod order_type;
+mod out_of_range_policy;
+#[cfg(feature = "juniper")]
+mod page_info;
+mod page_query;
+mod pager;
 mod pagination;
 mod pagination_options;
 mod relationship;
+#[cfg(feature = "utoipa")]
+mod required_capabilities;
 mod sql;
 mod types;
 
+pub use access_policy::*;
+pub use any_pagination_options::*;
+#[cfg(feature = "async-graphql")]
+pub use connection::*;
+#[cfg(feature = "json-api")]
+pub use json_api::*;
+#[cfg(feature = "odata")]
+pub use odata::*;
+pub use offset_error::*;
+pub use offset_pagination_options::*;
 pub use order_builder::*;
+pub use order_by_field::*;
 pub use order_by_options::*;
 pub use order_method::*;
+pub use order_method_limits::*;
 pub use order_type::*;
+pub use out_of_range_policy::*;
+#[cfg(feature = "juniper")]
+pub use page_info::*;
+pub use page_query::*;
+pub use pager::*;
 pub use pagination::*;
 pub use pagination_options::*;
 pub use relationship::*;
+#[cfg(feature = "utoipa")]
+pub use required_capabilities::*;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
 pub use sql::*;
 pub use types::*;
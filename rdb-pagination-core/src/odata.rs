@@ -0,0 +1,287 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter, Write},
+};
+
+use crate::{
+    AnyPaginationOptions, OrderByField, OrderByFields, OrderByOptions, OrderType, PageQuery,
+    Pagination, PaginationOptions,
+};
+
+/// An OData error object, which is the value of the top-level `error` member of an error response.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ODataError {
+    pub code:    String,
+    pub message: String,
+    /// The query option which caused the error, e.g. `$top`.
+    pub target:  String,
+}
+
+impl ODataError {
+    #[inline]
+    fn bad_request(target: &str, message: impl Into<String>) -> Self {
+        Self {
+            code:    String::from("BadRequest"),
+            message: message.into(),
+            target:  String::from(target),
+        }
+    }
+}
+
+impl Display for ODataError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{}: {}", self.target, self.message))
+    }
+}
+
+impl Error for ODataError {}
+
+/// The `@odata.count` and `@odata.nextLink` annotations of a collection response. They can be flattened into the response object.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ODataAnnotations {
+    #[serde(rename = "@odata.count", default, skip_serializing_if = "Option::is_none")]
+    pub count:     Option<usize>,
+    #[serde(rename = "@odata.nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+}
+
+/// The query options `$top`, `$skip`, `$orderby` and `$count` of an OData request.
+#[derive(Debug, Clone)]
+pub struct ODataQuery<T: OrderByOptions = ()> {
+    /// `None` means **unlimited**.
+    pub top:      Option<usize>,
+    pub skip:     u64,
+    pub order_by: T,
+    /// Whether `@odata.count` is requested.
+    pub count:    bool,
+}
+
+fn parse_top_skip(target: &str, value: &str) -> Result<u64, ODataError> {
+    value.parse::<u64>().map_err(|_| {
+        ODataError::bad_request(target, format!("`{value}` is not a non-negative integer"))
+    })
+}
+
+impl<T: OrderByFields> ODataQuery<T> {
+    /// Create an `ODataQuery` from the query options of an OData request. Keys and values should have been percent-decoded.
+    ///
+    /// `$orderby` is a comma-separated list of field names (see `OrderByField::as_str`), each of which may be followed by `asc` or `desc`. If it is absent, `T::default()` is used. `$skip` cannot be bigger than `PageQuery::MAX_OFFSET`. The ordering options are checked by `OrderByOptions::validate`. Other query options are ignored.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::ODataQuery;
+    /// #
+    /// let query =
+    ///     <ODataQuery>::from_parameters([("$top", "20"), ("$skip", "30")])
+    ///         .unwrap();
+    ///
+    /// assert_eq!(Some(20), query.top);
+    /// assert_eq!(30, query.skip);
+    /// ```
+    pub fn from_parameters<K: AsRef<str>, V: AsRef<str>>(
+        parameters: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, ODataError> {
+        let mut query =
+            Self {
+                top: None, skip: 0, order_by: T::default(), count: false
+            };
+
+        for (key, value) in parameters {
+            let (key, value) = (key.as_ref(), value.as_ref());
+
+            match key {
+                "$top" => {
+                    let top = parse_top_skip(key, value)?;
+
+                    query.top = Some(usize::try_from(top).map_err(|_| {
+                        ODataError::bad_request(key, format!("`{value}` is too large"))
+                    })?);
+                },
+                "$skip" => {
+                    let skip = parse_top_skip(key, value)?;
+
+                    if skip > PageQuery::MAX_OFFSET {
+                        return Err(ODataError::bad_request(
+                            key,
+                            format!("cannot be bigger than {}", PageQuery::MAX_OFFSET),
+                        ));
+                    }
+
+                    query.skip = skip;
+                },
+                "$count" => {
+                    query.count = match value {
+                        "true" => true,
+                        "false" => false,
+                        _ => {
+                            return Err(ODataError::bad_request(
+                                key,
+                                format!("`{value}` is not `true` or `false`"),
+                            ));
+                        },
+                    }
+                },
+                "$orderby" => query.order_by = Self::parse_order_by(value)?,
+                _ => (),
+            }
+        }
+
+        if let Err(error) = query.order_by.validate() {
+            return Err(ODataError::bad_request("$orderby", error.to_string()));
+        }
+
+        Ok(query)
+    }
+
+    fn parse_order_by(value: &str) -> Result<T, ODataError> {
+        let mut fields = Vec::new();
+
+        for item in value.split(',') {
+            let mut tokens = item.split_whitespace();
+
+            let name = tokens.next().unwrap_or_default();
+
+            let field = T::Field::from_name(name).ok_or_else(|| {
+                ODataError::bad_request("$orderby", format!("`{name}` is not a sortable property"))
+            })?;
+
+            let order_type = match tokens.next() {
+                None => OrderType::Asc,
+                Some(s) if s.eq_ignore_ascii_case("asc") => OrderType::Asc,
+                Some(s) if s.eq_ignore_ascii_case("desc") => OrderType::Desc,
+                Some(s) => {
+                    return Err(ODataError::bad_request(
+                        "$orderby",
+                        format!("`{s}` is not `asc` or `desc`"),
+                    ));
+                },
+            };
+
+            if let Some(s) = tokens.next() {
+                return Err(ODataError::bad_request("$orderby", format!("unexpected `{s}`")));
+            }
+
+            fields.push((field, order_type));
+        }
+
+        Ok(T::from_fields(&fields))
+    }
+}
+
+impl<T: OrderByOptions> ODataQuery<T> {
+    /// Returns the limit and the offset, which can be used with every `to_*_limit_offset` writer of `PageQuery`.
+    #[inline]
+    pub const fn page_query(&self) -> PageQuery {
+        PageQuery {
+            reversed: false, limit: self.top, offset: self.skip
+        }
+    }
+
+    /// Convert to `PaginationOptions` if `$skip` is a multiple of `$top` and the page number fits in `usize`, or `OffsetPaginationOptions` otherwise.
+    ///
+    /// `$top=0` requests an empty page, so it is converted to `OffsetPaginationOptions` with the limit `Some(0)`, because `items_per_page` being `0` means **unlimited**.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{AnyPaginationOptions, ODataQuery};
+    /// #
+    /// let query = <ODataQuery>::from_parameters([("$top", "0"), ("$skip", "20")]).unwrap();
+    ///
+    /// assert!(matches!(
+    ///     query.to_pagination_options(),
+    ///     AnyPaginationOptions::Offset(options) if options.offset == 20 && options.limit == Some(0)
+    /// ));
+    /// ```
+    #[inline]
+    pub fn to_pagination_options(&self) -> AnyPaginationOptions<T>
+    where
+        T: Clone, {
+        let options = PaginationOptions::default().order_by(self.order_by.clone());
+
+        match self.top {
+            Some(0) => options.at_offset(self.skip).limit(0).into(),
+            Some(top) if self.skip.is_multiple_of(top as u64) => {
+                match usize::try_from(self.skip / top as u64)
+                    .ok()
+                    .and_then(|page| page.checked_add(1))
+                {
+                    Some(page) => options.page(page).items_per_page(top).into(),
+                    None => options.items_per_page(top).at_offset(self.skip).into(),
+                }
+            },
+            Some(top) => options.items_per_page(top).at_offset(self.skip).into(),
+            None if self.skip > 0 => options.at_offset(self.skip).into(),
+            None => options.into(),
+        }
+    }
+
+    /// Create the `@odata.count` and `@odata.nextLink` annotations with the known total number of items. The next link keeps `$top` and advances `$skip` by it.
+    ///
+    /// `base_url` should include other query options, such as `$orderby` and `$count`, but not `$top` and `$skip`.
+    pub fn to_annotations(&self, base_url: &str, total_items: usize) -> ODataAnnotations {
+        let next_link = match self.top {
+            Some(top) if top > 0 => {
+                let next_skip = self.skip.saturating_add(top as u64);
+
+                if next_skip < total_items as u64 {
+                    Some(odata_link(base_url, top, next_skip))
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        ODataAnnotations {
+            count: if self.count { Some(total_items) } else { None },
+            next_link,
+        }
+    }
+}
+
+fn odata_link(base_url: &str, top: usize, skip: u64) -> String {
+    let mut url = String::from(base_url);
+
+    url.push(if base_url.contains('?') { '&' } else { '?' });
+
+    url.write_fmt(format_args!("$top={top}&$skip={skip}")).unwrap();
+
+    url
+}
+
+impl Pagination {
+    /// Create the `@odata.count` (if `count` is `true`) and `@odata.nextLink` annotations for the current page.
+    ///
+    /// `base_url` should include other query options, such as `$orderby` and `$count`, but not `$top` and `$skip`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::Pagination;
+    /// #
+    /// let pagination =
+    ///     Pagination::new().items_per_page(20).total_items(50).page(2);
+    ///
+    /// let annotations =
+    ///     pagination.to_odata_annotations("/Users?$count=true", true);
+    ///
+    /// assert_eq!(Some(50), annotations.count);
+    /// assert_eq!(
+    ///     Some("/Users?$count=true&$top=20&$skip=40"),
+    ///     annotations.next_link.as_deref()
+    /// );
+    /// ```
+    pub fn to_odata_annotations(&self, base_url: &str, count: bool) -> ODataAnnotations {
+        let items_per_page = self.get_items_per_page();
+        let next_skip = self.get_offset().saturating_add(items_per_page as u64);
+
+        let next_link = if items_per_page > 0 && next_skip < self.get_total_items() as u64 {
+            Some(odata_link(base_url, items_per_page, next_skip))
+        } else {
+            None
+        };
+
+        ODataAnnotations {
+            count: if count { Some(self.get_total_items()) } else { None },
+            next_link,
+        }
+    }
+}
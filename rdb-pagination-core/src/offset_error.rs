@@ -0,0 +1,29 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// Error returned by the checked offset calculation of `PaginationOptions` and `OffsetPaginationOptions`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OffsetError {
+    /// The offset computed from the page number and the number of items per page does not fit in `u64`.
+    Overflow,
+    /// The page number is bigger than the allowed maximum.
+    PageTooLarge { page: usize, max_page: usize },
+}
+
+impl Display for OffsetError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("the offset is too large"),
+            Self::PageTooLarge {
+                page,
+                max_page,
+            } => f.write_fmt(format_args!("page {page} exceeds the maximum page {max_page}")),
+        }
+    }
+}
+
+impl Error for OffsetError {}
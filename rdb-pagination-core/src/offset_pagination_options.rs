@@ -0,0 +1,306 @@
+use crate::{OffsetError, OrderByOptions, PageOutOfRangeError, PageQuery, Pagination};
+
+/// Struct representing offset-based pagination options, for clients which do not fetch items page by page, such as infinite scrolling.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rdb_pagination_core::OffsetPaginationOptions;
+/// #
+/// let options = OffsetPaginationOptions::new().offset(35).limit(20);
+/// ```
+///
+/// With the `serde` feature, this type implements each serde trait when `T` implements the same trait. Every field is optional when deserializing, but `offset` cannot be bigger than `PageQuery::MAX_OFFSET`. `order_by` is checked by `OrderByOptions::validate`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "OffsetPaginationOptionsInput<T>"))]
+pub struct OffsetPaginationOptions<T: OrderByOptions = ()> {
+    /// Offset of the first item.
+    ///
+    ///  Default: `0`.
+    pub offset:   u64,
+    /// Maximum number of items.
+    ///
+    /// * If the value is `None`, it means **unlimited**.
+    /// * If the value is `Some(0)`, no items are queried.
+    ///
+    ///  Default: `None`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub limit:    Option<usize>,
+    /// Ordering options which has to implement the `OrderByOptions` trait.
+    ///
+    /// Default: `()`.
+    pub order_by: T,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct OffsetPaginationOptionsInput<T> {
+    #[serde(default)]
+    offset:   u64,
+    #[serde(default)]
+    limit:    Option<usize>,
+    #[serde(default)]
+    order_by: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: OrderByOptions> TryFrom<OffsetPaginationOptionsInput<T>> for OffsetPaginationOptions<T> {
+    type Error = std::borrow::Cow<'static, str>;
+
+    #[inline]
+    fn try_from(value: OffsetPaginationOptionsInput<T>) -> Result<Self, Self::Error> {
+        if value.offset > PageQuery::MAX_OFFSET {
+            return Err(format!("offset cannot be bigger than {}", PageQuery::MAX_OFFSET).into());
+        }
+
+        if let Err(error) = value.order_by.validate() {
+            return Err(error.to_string().into());
+        }
+
+        Ok(Self {
+            offset: value.offset, limit: value.limit, order_by: value.order_by
+        })
+    }
+}
+
+impl OffsetPaginationOptions {
+    /// Create a new `OffsetPaginationOptions`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::OffsetPaginationOptions;
+    /// #
+    /// let options = OffsetPaginationOptions::new();
+    /// // equals to
+    /// let options =
+    ///     OffsetPaginationOptions {
+    ///         offset: 0, limit: None, order_by: ()
+    ///     };
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            offset: 0, limit: None, order_by: ()
+        }
+    }
+}
+
+impl<T: OrderByOptions> Default for OffsetPaginationOptions<T> {
+    /// Create a new `OffsetPaginationOptions<T>`.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            offset: 0, limit: None, order_by: T::default()
+        }
+    }
+}
+
+impl<T: OrderByOptions> OffsetPaginationOptions<T> {
+    /// Set the offset of the first item.
+    #[inline]
+    pub const fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+
+        self
+    }
+
+    /// Set the maximum number of items.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Set the ordering options which has to implement the `OrderByOptions` trait.
+    #[inline]
+    pub fn order_by(mut self, order_by: T) -> Self {
+        self.order_by = order_by;
+
+        self
+    }
+
+    /// Return `OffsetError::PageTooLarge` if the page which contains the first item is bigger than `max_page`, so that deep paging can be rejected before querying the database. Otherwise, return the offset.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{OffsetError, OffsetPaginationOptions};
+    /// #
+    /// let options = OffsetPaginationOptions::new().offset(1000).limit(20);
+    ///
+    /// assert_eq!(
+    ///     Err(OffsetError::PageTooLarge {
+    ///         page: 51, max_page: 50
+    ///     }),
+    ///     options.try_offset_with_max_page(50)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_offset_with_max_page(&self, max_page: usize) -> Result<u64, OffsetError> {
+        let page = Pagination::offset_to_page(self.offset, self.items_per_page());
+
+        if page > max_page {
+            return Err(OffsetError::PageTooLarge {
+                page,
+                max_page,
+            });
+        }
+
+        Ok(self.offset)
+    }
+
+    #[inline]
+    const fn items_per_page(&self) -> usize {
+        match self.limit {
+            Some(limit) => limit,
+            None => 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn to_page_query(&self) -> PageQuery {
+        PageQuery {
+            reversed: false, limit: self.limit, offset: self.offset
+        }
+    }
+
+    /// Create a `Pagination` at the offset with the known total number of items. The limit is used as the number of items per page, so the page number is the number of the page which contains the first item.
+    ///
+    /// `Pagination` cannot describe an empty page, so a limit of `Some(0)` is treated like `None` here.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::OffsetPaginationOptions;
+    /// #
+    /// let options = OffsetPaginationOptions::new().offset(35).limit(20);
+    ///
+    /// let pagination = options.to_pagination(50);
+    ///
+    /// assert_eq!(2, pagination.get_page());
+    /// assert_eq!(35, pagination.get_offset());
+    /// ```
+    #[inline]
+    pub const fn to_pagination(&self, total_items: usize) -> Pagination {
+        Pagination::new()
+            .out_of_range_policy(T::OUT_OF_RANGE_POLICY)
+            .items_per_page(self.items_per_page())
+            .total_items(total_items)
+            .at_offset(self.offset)
+    }
+
+    /// Like `to_pagination`, but returns `PageOutOfRangeError` if the page which contains the first item is out of range and `T::OUT_OF_RANGE_POLICY` is `OutOfRangePolicy::Error`.
+    #[inline]
+    pub const fn try_to_pagination(
+        &self,
+        total_items: usize,
+    ) -> Result<Pagination, PageOutOfRangeError> {
+        let pagination = self.to_pagination(total_items);
+
+        match pagination.validate() {
+            Ok(()) => Ok(pagination),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns whether the offset is not smaller than `total_items`, except that the offset `0` is always in range.
+    #[inline]
+    pub const fn is_out_of_range(&self, total_items: usize) -> bool {
+        self.offset > 0 && self.offset >= total_items as u64
+    }
+
+    /// Compute the limit and the offset with the known total number of items, querying from whichever end of the result set needs the smaller offset. The offset is not clamped.
+    ///
+    /// For items near the end, the returned `PageQuery` is reversed: the `ORDER BY` clause has to be reversed, and the fetched rows have to be reversed again in memory.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{OffsetPaginationOptions, PageQuery};
+    /// #
+    /// let options = OffsetPaginationOptions::new().offset(955).limit(20);
+    ///
+    /// assert_eq!(
+    ///     PageQuery {
+    ///         reversed: true, limit: Some(20), offset: 15
+    ///     },
+    ///     options.page_query(990)
+    /// );
+    /// ```
+    pub const fn page_query(&self, total_items: usize) -> PageQuery {
+        match self.limit {
+            Some(limit) if limit > 0 && self.offset < total_items as u64 => {
+                PageQuery::from_head_or_tail(self.offset as usize, limit, total_items)
+            },
+            _ => self.to_page_query(),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T: OrderByOptions> OffsetPaginationOptions<T> {
+    /// Generate a `LIMIT` with `OFFSET` clause for MySQL. See `PageQuery::to_mysql_limit_offset`.
+    #[inline]
+    pub fn to_mysql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.to_page_query().to_mysql_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for MySQL which checks the row number generated by `SqlOrderByComponent::format_mysql_row_number`. See `PageQuery::to_mysql_row_number_filter`.
+    #[inline]
+    pub fn to_mysql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mysql_row_number_filter(row_number_column_name, s)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T: OrderByOptions> OffsetPaginationOptions<T> {
+    /// Generate a `LIMIT` with `OFFSET` clause for SQLite. See `PageQuery::to_sqlite_limit_offset`.
+    #[inline]
+    pub fn to_sqlite_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.to_page_query().to_sqlite_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for SQLite which checks the row number generated by `SqlOrderByComponent::format_sqlite_row_number`. See `PageQuery::to_sqlite_row_number_filter`.
+    #[inline]
+    pub fn to_sqlite_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_sqlite_row_number_filter(row_number_column_name, s)
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl<T: OrderByOptions> OffsetPaginationOptions<T> {
+    /// Generate a `OFFSET` with `FETCH` clause for Microsoft SQL Server. See `PageQuery::to_mssql_limit_offset`.
+    #[inline]
+    pub fn to_mssql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.to_page_query().to_mssql_limit_offset(s)
+    }
+}
+
+#[cfg(any(feature = "mssql", feature = "mssql2008"))]
+impl<T: OrderByOptions> OffsetPaginationOptions<T> {
+    /// Generate a `WHERE` clause for Microsoft SQL Server which checks the row number generated by `SqlOrderByComponent::format_mssql_row_number`. See `PageQuery::to_mssql_row_number_filter`.
+    #[inline]
+    pub fn to_mssql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mssql_row_number_filter(row_number_column_name, s)
+    }
+}
+
+#[cfg(feature = "mssql2008")]
+impl<T: OrderByOptions> OffsetPaginationOptions<T> {
+    /// Generate a `WHERE` clause for Microsoft SQL Server 2008 and earlier (used for check the row number). See `PageQuery::to_mssql2008_limit_offset`.
+    #[inline]
+    pub fn to_mssql2008_limit_offset<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mssql2008_limit_offset(row_number_column_name, s)
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+use crate::{Name, OrderType, SqlJoin, TableColumn};
+
+/// How an ordering option has been resolved.
+#[derive(Debug, Clone)]
+pub enum OrderOptionResolution {
+    /// The order method is `0`.
+    Disabled,
+    /// The option is used as it is.
+    Applied { order_type: OrderType },
+    /// The option is used, but its column has been replaced with the column referencing it, so that the referenced table does not need to be joined.
+    Rewritten { table_column: TableColumn, order_type: OrderType },
+    /// The option is dropped because a unique option with a higher priority has already determined the order of its table.
+    DroppedByUnique { unique_table_column: TableColumn },
+    /// The option is not carried by any field. It is the tiebreaker appended in ascending order because no active option is unique in the base table.
+    Tiebreaker,
+}
+
+/// Explanation of a single ordering option.
+#[derive(Debug, Clone)]
+pub struct OrderOptionExplanation {
+    /// The name of the field which carries the option, if known.
+    pub field_name:   Option<Name>,
+    /// The table and the column which are declared for the option.
+    pub table_column: TableColumn,
+    pub resolution:   OrderOptionResolution,
+}
+
+/// Explanation of a single `JOIN` clause.
+#[derive(Debug, Clone)]
+pub struct JoinExplanation {
+    pub join:         SqlJoin,
+    /// The `ORDER BY` column which first needs the joined table.
+    pub table_column: TableColumn,
+}
+
+/// Report about how ordering options are resolved into `JOIN` and `ORDER BY` clauses.
+///
+/// Options are listed in the order of their priorities, followed by disabled options.
+#[derive(Debug, Clone, Default)]
+pub struct OrderByExplanation {
+    pub options: Vec<OrderOptionExplanation>,
+    pub joins:   Vec<JoinExplanation>,
+}
+
+struct DisplayTableColumn<'a>(&'a TableColumn);
+
+impl Display for DisplayTableColumn<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{}.{}", self.0.0, self.0.1))
+    }
+}
+
+impl Display for OrderOptionExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(field_name) = &self.field_name {
+            f.write_fmt(format_args!(
+                "{field_name} ({}): ",
+                DisplayTableColumn(&self.table_column)
+            ))?;
+        } else {
+            f.write_fmt(format_args!("{}: ", DisplayTableColumn(&self.table_column)))?;
+        }
+
+        match &self.resolution {
+            OrderOptionResolution::Disabled => f.write_str("disabled"),
+            OrderOptionResolution::Tiebreaker => f.write_str("appended as the tiebreaker, ASC"),
+            OrderOptionResolution::Applied {
+                order_type,
+            } => f.write_fmt(format_args!("applied, {}", order_type.as_str())),
+            OrderOptionResolution::Rewritten {
+                table_column,
+                order_type,
+            } => f.write_fmt(format_args!(
+                "rewritten to {} {}",
+                DisplayTableColumn(table_column),
+                order_type.as_str()
+            )),
+            OrderOptionResolution::DroppedByUnique {
+                unique_table_column,
+            } => f.write_fmt(format_args!(
+                "dropped, {} is unique and has a higher priority",
+                DisplayTableColumn(unique_table_column)
+            )),
+        }
+    }
+}
+
+impl Display for JoinExplanation {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "{} on {}.{} = {}.{}, needed by {}",
+            self.join.other_table_name,
+            self.join.other_table_name,
+            self.join.other_column_name,
+            self.join.using_table_name,
+            self.join.using_column_name,
+            DisplayTableColumn(&self.table_column)
+        ))
+    }
+}
+
+impl Display for OrderByExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("options:")?;
+
+        if self.options.is_empty() {
+            f.write_str(" none")?;
+        }
+
+        for option in self.options.iter() {
+            f.write_fmt(format_args!("\n    {option}"))?;
+        }
+
+        f.write_str("\njoins:")?;
+
+        if self.joins.is_empty() {
+            f.write_str(" none")?;
+        }
+
+        for join in self.joins.iter() {
+            f.write_fmt(format_args!("\n    {join}"))?;
+        }
+
+        Ok(())
+    }
+}
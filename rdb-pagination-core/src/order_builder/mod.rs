@@ -1,12 +1,14 @@
 mod errors;
+mod explanation;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub use errors::*;
+pub use explanation::*;
 
 use crate::{
-    NullStrategy, OrderMethod, OrderMethodValue, OrderType, Relationship, SqlJoin,
-    SqlOrderByComponent, TableColumn,
+    ColumnName, Name, NullStrategy, OrderMethod, OrderMethodValue, OrderType, Relationship,
+    SqlJoin, SqlOrderByComponent, TableColumn, TableName,
 };
 
 /// Hidden builder used by derive-generated code, not a stable application-facing API.
@@ -14,7 +16,18 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct OrderBuilder<T: OrderMethodValue = i8> {
     relationship:  Relationship,
-    order_options: Vec<(TableColumn, bool, NullStrategy, OrderMethod<T>)>,
+    order_options: Vec<OrderOption<T>>,
+    tiebreaker:    Option<TableColumn>,
+    partition_by:  Vec<TableColumn>,
+}
+
+#[derive(Debug, Clone)]
+struct OrderOption<T: OrderMethodValue> {
+    field_name:    Option<Name>,
+    table_column:  TableColumn,
+    unique:        bool,
+    null_strategy: NullStrategy,
+    order_method:  OrderMethod<T>,
 }
 
 impl<T: OrderMethodValue> OrderBuilder<T> {
@@ -23,9 +36,23 @@ impl<T: OrderMethodValue> OrderBuilder<T> {
         Self {
             relationship,
             order_options: Vec::with_capacity(capacity),
+            tiebreaker: None,
+            partition_by: Vec::new(),
         }
     }
 
+    /// Add a column for the `PARTITION BY` clause used by `build_partitioned`. The columns of tables which are not in the relationship are used as they are, without joins.
+    #[inline]
+    pub fn add_partition_column(&mut self, table_column: TableColumn) {
+        self.partition_by.push(table_column);
+    }
+
+    /// Set a unique column of the base table which is appended in ascending order when no active option makes the order deterministic.
+    #[inline]
+    pub fn set_tiebreaker(&mut self, column_name: ColumnName) {
+        self.tiebreaker = Some((self.relationship.table_name.clone(), column_name));
+    }
+
     #[inline]
     pub fn add_order_option_check(
         &mut self,
@@ -42,16 +69,17 @@ impl<T: OrderMethodValue> OrderBuilder<T> {
             return Err(OrderOptionError::TableNotRecognized);
         }
 
-        if self.order_options.iter().any(|(key, ..)| key == &table_column) {
+        if self.order_options.iter().any(|option| option.table_column == table_column) {
             return Err(OrderOptionError::TableColumnDuplicate);
         }
 
-        self.order_options.push((
+        self.order_options.push(OrderOption {
+            field_name: None,
             table_column,
             unique,
-            NullStrategy::Default,
-            OrderMethod(T::one()),
-        ));
+            null_strategy: NullStrategy::Default,
+            order_method: OrderMethod(T::one()),
+        });
 
         Ok(())
     }
@@ -65,111 +93,276 @@ impl<T: OrderMethodValue> OrderBuilder<T> {
         null_strategy: NullStrategy,
         order_method: OrderMethod<T>,
     ) {
-        if order_method.0 != T::zero() {
-            let unique = unique
-                || self.relationship.relationship.iter().any(|(_, value)| {
-                    value.table_name == table_column.0 && value.column_name == table_column.1
-                });
+        self.add_order_option_inner(None, table_column, unique, null_strategy, order_method)
+    }
 
-            self.order_options.push((table_column, unique, null_strategy, order_method));
-        }
+    /// The same as `add_order_option`, but also records the name of the field which carries the option, for `explain`.
+    #[inline]
+    pub fn add_named_order_option(
+        &mut self,
+        field_name: Name,
+        table_column: TableColumn,
+        unique: bool,
+        null_strategy: NullStrategy,
+        order_method: OrderMethod<T>,
+    ) {
+        self.add_order_option_inner(
+            Some(field_name),
+            table_column,
+            unique,
+            null_strategy,
+            order_method,
+        )
+    }
+
+    #[inline]
+    fn add_order_option_inner(
+        &mut self,
+        field_name: Option<Name>,
+        table_column: TableColumn,
+        unique: bool,
+        null_strategy: NullStrategy,
+        order_method: OrderMethod<T>,
+    ) {
+        let unique = unique
+            || self.relationship.relationship.iter().any(|(_, value)| {
+                value.table_name == table_column.0 && value.column_name == table_column.1
+            });
+
+        self.order_options.push(OrderOption {
+            field_name,
+            table_column,
+            unique,
+            null_strategy,
+            order_method,
+        });
     }
 
-    pub fn build(mut self) -> (Vec<SqlJoin>, Vec<SqlOrderByComponent>) {
-        self.order_options.sort_by_key(|(_, _, _, a)| a.0.abs());
+    #[inline]
+    pub fn build(self) -> (Vec<SqlJoin>, Vec<SqlOrderByComponent>) {
+        let (sql_joins, _, sql_order_by_units) = self.resolve(None);
 
-        {
-            // remove unnecessary options
+        (sql_joins, sql_order_by_units)
+    }
 
-            let mut unique_set = HashSet::new();
+    /// The same as `build`, but also returns the columns for the `PARTITION BY` clause. Joins needed by the partition columns are included and come first.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn build_partitioned(self) -> (Vec<SqlJoin>, Vec<TableColumn>, Vec<SqlOrderByComponent>) {
+        self.resolve(None)
+    }
 
-            let mut i = 0;
+    /// Resolve options like `build` does, but return a report about how each option and each join is handled.
+    #[inline]
+    pub fn explain(self) -> OrderByExplanation {
+        let mut explanation = OrderByExplanation::default();
 
-            while i < self.order_options.len() {
-                let remove = {
-                    let ((table_name, _), unique, ..) = &self.order_options[i];
+        self.resolve(Some(&mut explanation));
 
-                    let mut remove = false;
+        explanation
+    }
 
-                    if unique_set.contains(table_name) {
-                        remove = true
-                    } else {
-                        let related_table_names = self.relationship.get_related_tables(table_name);
+    #[allow(clippy::type_complexity)]
+    fn resolve(
+        self,
+        mut explanation: Option<&mut OrderByExplanation>,
+    ) -> (Vec<SqlJoin>, Vec<TableColumn>, Vec<SqlOrderByComponent>) {
+        let (mut order_options, disabled_options): (Vec<_>, Vec<_>) =
+            self.order_options.into_iter().partition(|option| option.order_method.0 != T::zero());
 
-                        for related_table_name in related_table_names {
-                            if unique_set.contains(related_table_name) {
-                                remove = true;
-                                break;
-                            }
-                        }
+        order_options.sort_by_key(|option| option.order_method.0.abs());
 
-                        if *unique {
-                            unique_set.insert(table_name.clone());
-                        }
+        let mut v = Vec::with_capacity(order_options.len() + 1);
+
+        let mut unique_map: HashMap<TableName, TableColumn> = HashMap::new();
+
+        {
+            // remove unnecessary options, and adjust options (check primary and foreign)
+
+            for option in order_options {
+                let table_name = &option.table_column.0;
+
+                let unique_table_column = unique_map.get(table_name).or_else(|| {
+                    self.relationship
+                        .get_related_tables(table_name)
+                        .into_iter()
+                        .find_map(|related_table_name| unique_map.get(related_table_name))
+                });
+
+                if let Some(unique_table_column) = unique_table_column {
+                    if let Some(explanation) = explanation.as_deref_mut() {
+                        explanation.options.push(OrderOptionExplanation {
+                            field_name:   option.field_name,
+                            table_column: option.table_column,
+                            resolution:   OrderOptionResolution::DroppedByUnique {
+                                unique_table_column: unique_table_column.clone(),
+                            },
+                        });
                     }
 
-                    remove
+                    continue;
+                }
+
+                if option.unique {
+                    unique_map.insert(table_name.clone(), option.table_column.clone());
+                }
+
+                let order_type = OrderType::from_order_method(option.order_method);
+
+                let table_column = match self.relationship.relationship.get(table_name) {
+                    Some(attr) if attr.column_name == option.table_column.1 => {
+                        (attr.foreign_table_name.clone(), attr.foreign_column_name.clone())
+                    },
+                    _ => option.table_column.clone(),
                 };
 
-                if remove {
-                    self.order_options.remove(i);
-                } else {
-                    i += 1;
+                if let Some(explanation) = explanation.as_deref_mut() {
+                    let resolution = if table_column == option.table_column {
+                        OrderOptionResolution::Applied {
+                            order_type,
+                        }
+                    } else {
+                        OrderOptionResolution::Rewritten {
+                            table_column: table_column.clone(),
+                            order_type,
+                        }
+                    };
+
+                    explanation.options.push(OrderOptionExplanation {
+                        field_name: option.field_name,
+                        table_column: option.table_column,
+                        resolution,
+                    });
                 }
+
+                v.push((table_column, option.null_strategy, order_type));
             }
         }
 
+        if let Some(tiebreaker) = self.tiebreaker
+            && !unique_map.contains_key(&self.relationship.table_name)
+            && !v.iter().any(|(table_column, ..)| table_column == &tiebreaker)
         {
-            // adjust options (check primary and foreign)
-            for (table_column, ..) in self.order_options.iter_mut() {
-                if let Some(attr) = self.relationship.relationship.get(&table_column.0)
-                    && attr.column_name == table_column.1
-                {
-                    *table_column =
-                        (attr.foreign_table_name.clone(), attr.foreign_column_name.clone());
-                }
+            if let Some(explanation) = explanation.as_deref_mut() {
+                explanation.options.push(OrderOptionExplanation {
+                    field_name:   None,
+                    table_column: tiebreaker.clone(),
+                    resolution:   OrderOptionResolution::Tiebreaker,
+                });
             }
-        }
 
-        // fetch OrderType
+            v.push((tiebreaker, NullStrategy::Default, OrderType::Asc));
+        }
 
-        let v = self
-            .order_options
-            .into_iter()
-            .map(|((table_name, column_name), _, null_strategy, order_method)| {
-                (table_name, column_name, null_strategy, OrderType::from_order_method(order_method))
-            })
-            .collect::<Vec<_>>();
+        if let Some(explanation) = explanation.as_deref_mut() {
+            explanation.options.extend(disabled_options.into_iter().map(|option| {
+                OrderOptionExplanation {
+                    field_name:   option.field_name,
+                    table_column: option.table_column,
+                    resolution:   OrderOptionResolution::Disabled,
+                }
+            }));
+        }
 
         // generate sql tokens
 
+        // the base table is referred to by its alias in the generated SQL
+        let alias = |table_name: TableName| match &self.relationship.alias {
+            Some(alias) if table_name == self.relationship.table_name => alias.clone(),
+            _ => table_name,
+        };
+
         let mut joined = HashSet::new();
         let mut sql_joins = Vec::new();
-        let mut sql_order_by_units = Vec::new();
+        let mut sql_partition_by = Vec::with_capacity(self.partition_by.len());
+        let mut sql_order_by_units = Vec::with_capacity(v.len());
 
-        for (table_name, column_name, null_strategy, order_type) in v {
-            let related_table_names = self.relationship.get_related_tables(&table_name);
+        for table_column in self.partition_by {
+            if self.relationship.table_name != table_column.0
+                && !self.relationship.relationship.contains_key(&table_column.0)
+            {
+                sql_partition_by.push(table_column);
 
-            for related_table_name in related_table_names.into_iter().rev().chain([&table_name]) {
-                if joined.contains(related_table_name) {
-                    continue;
-                }
+                continue;
+            }
 
-                joined.insert(related_table_name.clone());
+            let table_column = match self.relationship.relationship.get(&table_column.0) {
+                Some(attr) if attr.column_name == table_column.1 => {
+                    (attr.foreign_table_name.clone(), attr.foreign_column_name.clone())
+                },
+                _ => table_column,
+            };
+
+            add_joins(
+                &self.relationship,
+                &table_column,
+                &mut joined,
+                &mut sql_joins,
+                explanation.as_deref_mut(),
+            );
+
+            sql_partition_by.push((alias(table_column.0), table_column.1));
+        }
 
-                if let Some(attrs) = self.relationship.relationship.get(related_table_name) {
-                    sql_joins.push(SqlJoin::from_table_column_attributes(attrs));
-                }
-            }
+        for (table_column, null_strategy, order_type) in v {
+            add_joins(
+                &self.relationship,
+                &table_column,
+                &mut joined,
+                &mut sql_joins,
+                explanation.as_deref_mut(),
+            );
+
+            let (table_name, column_name) = table_column;
 
             sql_order_by_units.push(SqlOrderByComponent {
-                table_name,
+                table_name: alias(table_name),
                 column_name,
                 order_type,
                 null_strategy,
             });
         }
 
-        (sql_joins, sql_order_by_units)
+        (sql_joins, sql_partition_by, sql_order_by_units)
+    }
+}
+
+/// Push the joins needed by the table of `table_column` which have not been joined.
+fn add_joins(
+    relationship: &Relationship,
+    table_column: &TableColumn,
+    joined: &mut HashSet<TableName>,
+    sql_joins: &mut Vec<SqlJoin>,
+    mut explanation: Option<&mut OrderByExplanation>,
+) {
+    let table_name = &table_column.0;
+
+    let related_table_names = relationship.get_related_tables(table_name);
+
+    for related_table_name in related_table_names.into_iter().rev().chain([table_name]) {
+        if joined.contains(related_table_name) {
+            continue;
+        }
+
+        joined.insert(related_table_name.clone());
+
+        if let Some(attrs) = relationship.relationship.get(related_table_name) {
+            let mut join = SqlJoin::from_table_column_attributes(attrs);
+
+            if let Some(alias) = &relationship.alias
+                && join.using_table_name == relationship.table_name
+            {
+                join.using_table_name = alias.clone();
+            }
+
+            if let Some(explanation) = explanation.as_deref_mut() {
+                explanation.joins.push(JoinExplanation {
+                    join:         join.clone(),
+                    table_column: table_column.clone(),
+                });
+            }
+
+            sql_joins.push(join);
+        }
     }
 }
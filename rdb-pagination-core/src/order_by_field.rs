@@ -0,0 +1,95 @@
+use std::{convert::Infallible, fmt::Debug, hash::Hash};
+
+use crate::{OrderByOptions, OrderType};
+
+/// A sortable field of ordering options. It is usually an enum generated by `#[derive(OrderByOptions)]`, with one variant per annotated field.
+pub trait OrderByField: Debug + Copy + Eq + Hash + 'static {
+    /// All fields, in the order of declaration.
+    const FIELDS: &'static [Self];
+
+    /// Returns the name of the field, i.e. the name of the struct field.
+    fn as_str(&self) -> &'static str;
+
+    /// Find a field by its name.
+    #[inline]
+    fn from_name(name: &str) -> Option<Self> {
+        Self::FIELDS.iter().find(|field| field.as_str() == name).copied()
+    }
+}
+
+/// Ordering options which can be converted from and to an ordered list of fields and order types.
+///
+/// The first item of the list has the highest priority.
+pub trait OrderByFields: OrderByOptions {
+    type Field: OrderByField;
+
+    /// Create ordering options from an ordered list of fields and order types. Fields which are not listed are disabled, and repeated fields are ignored.
+    fn from_fields(fields: &[(Self::Field, OrderType)]) -> Self;
+
+    /// Returns the enabled fields with their order types, ordered by priority.
+    fn to_fields(&self) -> Vec<(Self::Field, OrderType)>;
+}
+
+/// No fields.
+impl OrderByField for Infallible {
+    const FIELDS: &'static [Self] = &[];
+
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        match *self {}
+    }
+}
+
+impl OrderByFields for () {
+    type Field = Infallible;
+
+    #[inline]
+    fn from_fields(_fields: &[(Self::Field, OrderType)]) -> Self {}
+
+    #[inline]
+    fn to_fields(&self) -> Vec<(Self::Field, OrderType)> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub fn serialize_order_by_field<F: OrderByField, S: serde::Serializer>(
+    field: &F,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(field.as_str())
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub fn deserialize_order_by_field<'de, F: OrderByField, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<F, D::Error> {
+    use std::{fmt, fmt::Formatter, marker::PhantomData};
+
+    use serde::de::{Error, Visitor};
+
+    struct FieldVisitor<F>(PhantomData<F>);
+
+    impl<F: OrderByField> Visitor<'_> for FieldVisitor<F> {
+        type Value = F;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a field name")
+        }
+
+        #[inline]
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            F::from_name(v).ok_or_else(|| {
+                E::custom(format_args!(
+                    "unknown field `{v}`, expected one of {:?}",
+                    F::FIELDS.iter().map(F::as_str).collect::<Vec<&str>>()
+                ))
+            })
+        }
+    }
+
+    deserializer.deserialize_str(FieldVisitor(PhantomData))
+}
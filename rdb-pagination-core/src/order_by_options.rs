@@ -1,12 +1,79 @@
-use crate::{SqlJoin, SqlOrderByComponent};
+use crate::{
+    AccessDeniedError, AccessPolicy, OrderByExplanation, OrderMethodError, OutOfRangePolicy,
+    SqlJoin, SqlOrderByComponent, TableColumn,
+};
 
 /// Options for the `ORDER BY` clause.
 pub trait OrderByOptions: Default {
+    /// What `PaginationOptions::to_pagination` does with a page number which is bigger than the total number of pages.
+    const OUT_OF_RANGE_POLICY: OutOfRangePolicy = OutOfRangePolicy::Clamp;
+
+    /// The options which require capabilities, as `(field, capability)` pairs.
+    const REQUIRED_CAPABILITIES: &'static [(&'static str, &'static str)] = &[];
+
     /// Create objects for generating a SQL statement.
     #[inline]
     fn to_sql(&self) -> (Vec<SqlJoin>, Vec<SqlOrderByComponent>) {
         (Vec::new(), Vec::new())
     }
+
+    /// Create objects for generating a SQL statement like `to_sql`, but check the options which require capabilities against the `policy` first.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn to_sql_with<F: Fn(&str) -> bool>(
+        &self,
+        _policy: &AccessPolicy<F>,
+    ) -> Result<(Vec<SqlJoin>, Vec<SqlOrderByComponent>), AccessDeniedError> {
+        Ok(self.to_sql())
+    }
+
+    /// Create objects for generating a SQL statement which numbers rows within each partition of the `partition_by` columns, e.g. for fetching the top N rows per group.
+    ///
+    /// Like the ordering columns, a partition column which refers to the primary key of a joined table is replaced with the column referencing it, and joins needed by the partition columns are included. Columns of tables which are not declared are used as they are.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn to_partitioned_sql(
+        &self,
+        partition_by: Vec<TableColumn>,
+    ) -> (Vec<SqlJoin>, Vec<TableColumn>, Vec<SqlOrderByComponent>) {
+        let (joins, order_by_components) = self.to_sql();
+
+        (joins, partition_by, order_by_components)
+    }
+
+    /// Create objects for generating a SQL statement like `to_partitioned_sql`, but check the options which require capabilities against the `policy` first.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn to_partitioned_sql_with<F: Fn(&str) -> bool>(
+        &self,
+        partition_by: Vec<TableColumn>,
+        policy: &AccessPolicy<F>,
+    ) -> Result<(Vec<SqlJoin>, Vec<TableColumn>, Vec<SqlOrderByComponent>), AccessDeniedError> {
+        let (joins, order_by_components) = self.to_sql_with(policy)?;
+
+        Ok((joins, partition_by, order_by_components))
+    }
+
+    /// Check the `OrderMethod`s controlled by clients, e.g. reject duplicate priorities. With the `serde` feature, `PaginationOptions` calls it when being deserialized.
+    #[inline]
+    fn validate(&self) -> Result<(), OrderMethodError> {
+        Ok(())
+    }
+
+    /// Explain how the options are resolved by `to_sql`, i.e. which options are applied, dropped or rewritten, and why each join is added.
+    #[inline]
+    fn explain(&self) -> OrderByExplanation {
+        OrderByExplanation::default()
+    }
+
+    /// Explain how the options are resolved by `to_sql_with`. The options dropped by the `policy` do not appear in the explanation.
+    #[inline]
+    fn explain_with<F: Fn(&str) -> bool>(
+        &self,
+        _policy: &AccessPolicy<F>,
+    ) -> Result<OrderByExplanation, AccessDeniedError> {
+        Ok(self.explain())
+    }
 }
 
 impl OrderByOptions for () {}
@@ -1,6 +1,14 @@
-use std::{fmt::Debug, hash::Hash, num::ParseIntError, str::FromStr};
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::Hash,
+    num::{NonZeroI16, ParseIntError, TryFromIntError},
+    str::FromStr,
+};
 
-/// Value for `OrderMethod`. This should be `i8` or `i16`.
+use crate::OrderType;
+
+/// Value for `OrderMethod`. This is implemented for `i8`, `i16`, `i32`, `i64` and `OptionNonZeroI16`.
 pub trait OrderMethodValue:
     Debug
     + Default
@@ -16,7 +24,14 @@ pub trait OrderMethodValue:
 
     fn one() -> Self;
 
+    /// Returns the absolute value, which saturates instead of overflowing, e.g. for `i32::MIN`.
     fn abs(&self) -> Self;
+
+    /// Converts an `isize`, returning `None` if it is out of range.
+    fn from_isize(value: isize) -> Option<Self>;
+
+    /// Converts to an `isize`, saturating if it is out of range.
+    fn to_isize(&self) -> isize;
 }
 
 impl OrderMethodValue for i8 {
@@ -32,7 +47,17 @@ impl OrderMethodValue for i8 {
 
     #[inline]
     fn abs(&self) -> Self {
-        i8::abs(*self)
+        i8::saturating_abs(*self)
+    }
+
+    #[inline]
+    fn from_isize(value: isize) -> Option<Self> {
+        i8::try_from(value).ok()
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        *self as isize
     }
 }
 
@@ -49,10 +74,198 @@ impl OrderMethodValue for i16 {
 
     #[inline]
     fn abs(&self) -> Self {
-        i16::abs(*self)
+        i16::saturating_abs(*self)
+    }
+
+    #[inline]
+    fn from_isize(value: isize) -> Option<Self> {
+        i16::try_from(value).ok()
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        *self as isize
     }
 }
 
+impl OrderMethodValue for i32 {
+    #[inline]
+    fn zero() -> Self {
+        0
+    }
+
+    #[inline]
+    fn one() -> Self {
+        1
+    }
+
+    #[inline]
+    fn abs(&self) -> Self {
+        i32::saturating_abs(*self)
+    }
+
+    #[inline]
+    fn from_isize(value: isize) -> Option<Self> {
+        i32::try_from(value).ok()
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        isize::try_from(*self).unwrap_or(if *self < 0 { isize::MIN } else { isize::MAX })
+    }
+}
+
+impl OrderMethodValue for i64 {
+    #[inline]
+    fn zero() -> Self {
+        0
+    }
+
+    #[inline]
+    fn one() -> Self {
+        1
+    }
+
+    #[inline]
+    fn abs(&self) -> Self {
+        i64::saturating_abs(*self)
+    }
+
+    #[inline]
+    fn from_isize(value: isize) -> Option<Self> {
+        i64::try_from(value).ok()
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        isize::try_from(*self).unwrap_or(if *self < 0 { isize::MIN } else { isize::MAX })
+    }
+}
+
+/// A value for `OrderMethod` which is `None` when the ordering is disabled, instead of `0`.
+///
+/// It is compared as an `i16`, where `None` is `0`. With the `serde` feature, it is serialized as an `i16`, and `0` is deserialized as `None`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct OptionNonZeroI16(pub Option<NonZeroI16>);
+
+impl OptionNonZeroI16 {
+    /// Get the value as an `i16`, where `None` is `0`.
+    #[inline]
+    pub const fn get(&self) -> i16 {
+        match self.0 {
+            Some(value) => value.get(),
+            None => 0,
+        }
+    }
+}
+
+impl From<i16> for OptionNonZeroI16 {
+    #[inline]
+    fn from(value: i16) -> Self {
+        Self(NonZeroI16::new(value))
+    }
+}
+
+impl From<OptionNonZeroI16> for i16 {
+    #[inline]
+    fn from(value: OptionNonZeroI16) -> Self {
+        value.get()
+    }
+}
+
+impl From<OptionNonZeroI16> for i32 {
+    #[inline]
+    fn from(value: OptionNonZeroI16) -> Self {
+        value.get().into()
+    }
+}
+
+impl TryFrom<i32> for OptionNonZeroI16 {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        i16::try_from(value).map(Self::from)
+    }
+}
+
+impl Ord for OptionNonZeroI16 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl PartialOrd for OptionNonZeroI16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for OptionNonZeroI16 {
+    type Err = ParseIntError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(Self::from)
+    }
+}
+
+impl OrderMethodValue for OptionNonZeroI16 {
+    #[inline]
+    fn zero() -> Self {
+        Self(None)
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Self(NonZeroI16::new(1))
+    }
+
+    #[inline]
+    fn abs(&self) -> Self {
+        Self(self.0.map(NonZeroI16::saturating_abs))
+    }
+
+    #[inline]
+    fn from_isize(value: isize) -> Option<Self> {
+        i16::try_from(value).ok().map(Self::from)
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        self.get() as isize
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for OptionNonZeroI16 {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i16(self.get())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OptionNonZeroI16 {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i16::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::PartialSchema for OptionNonZeroI16 {
+    #[inline]
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        <i16 as utoipa::PartialSchema>::schema()
+    }
+}
+
+#[cfg(feature = "utoipa")]
+impl utoipa::ToSchema for OptionNonZeroI16 {}
+
 /// An integer value for ordering.
 ///
 /// * **0**: Disabled
@@ -61,17 +274,110 @@ impl OrderMethodValue for i16 {
 /// * Absolute value indicates priority; the smaller the value, the more important it is.
 ///
 /// With the `serde` feature, this type is serialized as its inner integer value.
+///
+/// With the `async-graphql` feature, this type is a GraphQL input type represented as its inner integer value.
+///
+/// With the `juniper` feature, this type is a GraphQL scalar named `OrderMethod`, represented as an `Int`.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "juniper", derive(juniper::GraphQLScalar))]
+#[cfg_attr(
+    feature = "juniper",
+    graphql(
+        name = "OrderMethod",
+        with = juniper_order_method,
+        parse_token(i32),
+        where(T: Into<i32> + TryFrom<i32>)
+    )
+)]
 pub struct OrderMethod<T: OrderMethodValue = i8>(
     #[cfg_attr(feature = "utoipa", schema(inline))] pub T,
 );
 
+impl<T: OrderMethodValue> OrderMethod<T> {
+    /// Create an `OrderMethod` from a priority (starting from `1`) and an order type.
+    ///
+    /// Returns `None` if `priority` is `0` or out of the range of `T`.
+    #[inline]
+    pub fn from_priority(priority: usize, order_type: OrderType) -> Option<Self> {
+        if priority == 0 {
+            return None;
+        }
+
+        let value = isize::try_from(priority).ok()?;
+
+        let value = match order_type {
+            OrderType::Asc => value,
+            OrderType::Desc => -value,
+        };
+
+        T::from_isize(value).map(Self)
+    }
+
+    /// Returns the order type, or `None` if the order method is `0` (disabled).
+    #[inline]
+    pub fn order_type(&self) -> Option<OrderType> {
+        if self.0 == T::zero() { None } else { Some(OrderType::from_order_method(*self)) }
+    }
+}
+
 impl<T: OrderMethodValue> From<T> for OrderMethod<T> {
     #[inline]
     fn from(value: T) -> Self {
         Self(value)
     }
 }
+
+#[cfg(feature = "async-graphql")]
+impl<T: OrderMethodValue + async_graphql::InputType> async_graphql::InputType for OrderMethod<T> {
+    type RawValueType = T::RawValueType;
+
+    #[inline]
+    fn type_name() -> std::borrow::Cow<'static, str> {
+        T::type_name()
+    }
+
+    #[inline]
+    fn create_type_info(registry: &mut async_graphql::registry::Registry) -> String {
+        T::create_type_info(registry)
+    }
+
+    #[inline]
+    fn parse(value: Option<async_graphql::Value>) -> async_graphql::InputValueResult<Self> {
+        T::parse(value).map(Self).map_err(async_graphql::InputValueError::propagate)
+    }
+
+    #[inline]
+    fn to_value(&self) -> async_graphql::Value {
+        self.0.to_value()
+    }
+
+    #[inline]
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        self.0.as_raw_value()
+    }
+}
+
+#[cfg(feature = "juniper")]
+mod juniper_order_method {
+    use juniper::{Scalar, ScalarValue};
+
+    use super::{OrderMethod, OrderMethodValue};
+
+    pub(super) fn to_output<S: ScalarValue, T: OrderMethodValue + Into<i32>>(
+        v: &OrderMethod<T>,
+    ) -> S {
+        v.0.into().into()
+    }
+
+    pub(super) fn from_input<S: ScalarValue, T: OrderMethodValue + TryFrom<i32>>(
+        v: &Scalar<S>,
+    ) -> Result<OrderMethod<T>, Box<str>> {
+        v.try_to_int()
+            .and_then(|v| T::try_from(v).ok())
+            .map(OrderMethod)
+            .ok_or_else(|| format!("Expected an order method, found: {v}").into())
+    }
+}
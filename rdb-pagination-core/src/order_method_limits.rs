@@ -0,0 +1,129 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+use crate::{OrderMethod, OrderMethodValue};
+
+/// Limits of the `OrderMethod`s which are controlled by clients, checked by `OrderByOptions::validate`.
+///
+/// Duplicate absolute values (priorities) of enabled `OrderMethod`s are always rejected.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OrderMethodLimits {
+    /// The maximum absolute value. `None` means the maximum of the value type.
+    pub max_priority: Option<usize>,
+    /// The maximum number of enabled `OrderMethod`s. `None` means unlimited.
+    pub max_fields:   Option<usize>,
+}
+
+impl OrderMethodLimits {
+    /// Check named `OrderMethod`s against the limits.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{OrderMethod, OrderMethodLimits};
+    /// #
+    /// let limits = OrderMethodLimits::default();
+    ///
+    /// assert!(
+    ///     limits
+    ///         .validate(&[("name", OrderMethod(1i8)), ("id", OrderMethod(2))])
+    ///         .is_ok()
+    /// );
+    /// assert!(
+    ///     limits
+    ///         .validate(&[("name", OrderMethod(1i8)), ("id", OrderMethod(-1))])
+    ///         .is_err()
+    /// );
+    /// ```
+    pub fn validate<T: OrderMethodValue>(
+        &self,
+        order_methods: &[(&'static str, OrderMethod<T>)],
+    ) -> Result<(), OrderMethodError> {
+        let mut enabled = order_methods
+            .iter()
+            .filter(|(_, order_method)| order_method.0 != T::zero())
+            .map(|(field, order_method)| (*field, order_method.0.to_isize().unsigned_abs()))
+            .collect::<Vec<_>>();
+
+        if let Some(max_priority) = self.max_priority
+            && let Some(&(field, priority)) =
+                enabled.iter().find(|(_, priority)| *priority > max_priority)
+        {
+            return Err(OrderMethodError::PriorityTooLarge {
+                field,
+                priority,
+                max_priority,
+            });
+        }
+
+        enabled.sort_by_key(|(_, priority)| *priority);
+
+        if let Some(window) = enabled.windows(2).find(|window| window[0].1 == window[1].1) {
+            return Err(OrderMethodError::DuplicatePriority {
+                priority: window[0].1,
+                fields:   [window[0].0, window[1].0],
+            });
+        }
+
+        if let Some(max_fields) = self.max_fields
+            && enabled.len() > max_fields
+        {
+            return Err(OrderMethodError::TooManyFields {
+                fields: enabled.into_iter().map(|(field, _)| field).collect(),
+                max_fields,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when `OrderMethod`s controlled by clients violate `OrderMethodLimits`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum OrderMethodError {
+    /// The absolute value of the `OrderMethod` of a field is bigger than the allowed maximum.
+    PriorityTooLarge { field: &'static str, priority: usize, max_priority: usize },
+    /// Two fields have the same absolute value, so their order would be decided by the declaration order.
+    DuplicatePriority { priority: usize, fields: [&'static str; 2] },
+    /// Too many fields are enabled. `fields` are the enabled fields, sorted by priority.
+    TooManyFields { fields: Vec<&'static str>, max_fields: usize },
+}
+
+impl Display for OrderMethodError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PriorityTooLarge {
+                field,
+                priority,
+                max_priority,
+            } => f.write_fmt(format_args!(
+                "the priority {priority} of `{field}` exceeds the maximum priority {max_priority}"
+            )),
+            Self::DuplicatePriority {
+                priority,
+                fields: [a, b],
+            } => f.write_fmt(format_args!("`{a}` and `{b}` have the same priority {priority}")),
+            Self::TooManyFields {
+                fields,
+                max_fields,
+            } => {
+                f.write_fmt(format_args!(
+                    "{} fields are used for ordering, but at most {max_fields} are allowed:",
+                    fields.len()
+                ))?;
+
+                for (i, field) in fields.iter().enumerate() {
+                    f.write_str(if i == 0 { " `" } else { ", `" })?;
+                    f.write_str(field)?;
+                    f.write_str("`")?;
+                }
+
+                Ok(())
+            },
+        }
+    }
+}
+
+impl Error for OrderMethodError {}
@@ -1,9 +1,20 @@
 use crate::{OrderMethod, OrderMethodValue};
 
 /// Enum representing the order type.
-#[derive(Debug, Clone, Copy)]
+///
+/// With the `serde` feature, this type is serialized as `"asc"` or `"desc"`. `"ASC"` and `"DESC"` are also accepted when deserializing.
+///
+/// With the `async-graphql` feature, this type is a GraphQL enum with the `ASC` and `DESC` values.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(rename_all = "lowercase"))]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
 pub enum OrderType {
+    #[cfg_attr(feature = "serde", serde(alias = "ASC"))]
     Asc,
+    #[cfg_attr(feature = "serde", serde(alias = "DESC"))]
     Desc,
 }
 
@@ -20,6 +31,15 @@ impl OrderType {
         if order_method > OrderMethod(T::zero()) { Self::Asc } else { Self::Desc }
     }
 
+    /// Returns the opposite order type.
+    #[inline]
+    pub const fn reverse(&self) -> Self {
+        match self {
+            Self::Asc => Self::Desc,
+            Self::Desc => Self::Asc,
+        }
+    }
+
     /// Returns the string representation of the order type.
     #[inline]
     pub const fn as_str(&self) -> &'static str {
@@ -0,0 +1,67 @@
+use std::{
+    error::Error,
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// What to do with a page number which is bigger than the total number of pages.
+///
+/// With the `serde` feature, this type is serialized as `"clamp"`, `"empty"` or `"error"`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(rename_all = "lowercase"))]
+pub enum OutOfRangePolicy {
+    /// Serve the last page instead.
+    #[default]
+    Clamp,
+    /// Keep the page number, so that the page has no items.
+    Empty,
+    /// Keep the page number like `Empty`, but report `PageOutOfRangeError` when validating.
+    Error,
+}
+
+impl OutOfRangePolicy {
+    /// Apply the policy to a page number. A page number of `0` is always changed to `1`.
+    #[inline]
+    pub const fn apply(&self, page: usize, total_pages: usize) -> usize {
+        match self {
+            Self::Clamp => {
+                if page == 0 || total_pages == 0 {
+                    1
+                } else if page > total_pages {
+                    total_pages
+                } else {
+                    page
+                }
+            },
+            Self::Empty | Self::Error => {
+                if page == 0 {
+                    1
+                } else {
+                    page
+                }
+            },
+        }
+    }
+}
+
+/// Error returned when the page number is bigger than the total number of pages and the `OutOfRangePolicy` is `Error`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PageOutOfRangeError {
+    pub page:        usize,
+    pub total_pages: usize,
+}
+
+impl Display for PageOutOfRangeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "page {} is out of range, the total number of pages is {}",
+            self.page, self.total_pages
+        ))
+    }
+}
+
+impl Error for PageOutOfRangeError {}
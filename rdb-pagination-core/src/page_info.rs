@@ -0,0 +1,67 @@
+use crate::{PageQuery, Pagination};
+
+/// The Relay `PageInfo` object. Cursors are offsets of items in the whole result set.
+#[derive(Debug, Clone, Eq, PartialEq, juniper::GraphQLObject)]
+pub struct PageInfo {
+    /// Whether there are items before the current page.
+    pub has_previous_page: bool,
+    /// Whether there are items after the current page.
+    pub has_next_page:     bool,
+    /// The cursor of the first item on the current page.
+    pub start_cursor:      Option<String>,
+    /// The cursor of the last item on the current page.
+    pub end_cursor:        Option<String>,
+}
+
+impl PageInfo {
+    /// Create a `PageInfo` for `count` items starting from `offset`. Items after `total_items` are ignored, so an offset which is out of range has no cursors.
+    #[inline]
+    pub fn new(offset: usize, count: usize, total_items: usize) -> Self {
+        let count = count.min(total_items.saturating_sub(offset));
+
+        let (start_cursor, end_cursor) = if count == 0 {
+            (None, None)
+        } else {
+            (Some(offset.to_string()), Some(offset.saturating_add(count - 1).to_string()))
+        };
+
+        Self {
+            has_previous_page: offset > 0,
+            has_next_page: offset.saturating_add(count) < total_items,
+            start_cursor,
+            end_cursor,
+        }
+    }
+}
+
+impl Pagination {
+    /// Create a `PageInfo` for the current page.
+    #[inline]
+    pub fn to_page_info(&self) -> PageInfo {
+        let total_items = self.get_total_items();
+        let offset = usize::try_from(self.get_offset()).unwrap_or(usize::MAX);
+
+        let count = match self.get_items_per_page() {
+            0 => total_items.saturating_sub(offset),
+            items_per_page => items_per_page.min(total_items.saturating_sub(offset)),
+        };
+
+        PageInfo::new(offset, count, total_items)
+    }
+}
+
+impl PageQuery {
+    /// Create a `PageInfo` for the `count` items fetched with this `PageQuery`.
+    #[inline]
+    pub fn to_page_info(&self, total_items: usize, count: usize) -> PageInfo {
+        let offset = usize::try_from(self.offset).unwrap_or(usize::MAX);
+
+        let offset = if self.reversed {
+            total_items.saturating_sub(offset.saturating_add(count))
+        } else {
+            offset
+        };
+
+        PageInfo::new(offset, count, total_items)
+    }
+}
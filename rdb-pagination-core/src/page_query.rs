@@ -0,0 +1,339 @@
+/// Struct representing the `LIMIT` and the `OFFSET` used to fetch a page, and the direction of the ordering.
+///
+/// It is usually created by `PaginationOptions::page_query`, which queries a deep page from the tail of the result set when that needs a smaller offset.
+///
+/// If `reversed` is `true`, the `ORDER BY` clause has to be reversed (see `SqlOrderByComponentsOps::reverse_order`), and the fetched rows have to be reversed again in memory.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PageQuery {
+    /// Whether the query uses the reversed ordering.
+    pub reversed: bool,
+    /// `None` means **unlimited**.
+    pub limit:    Option<usize>,
+    pub offset:   u64,
+}
+
+impl PageQuery {
+    /// The largest offset which is accepted when deserializing pagination options. It is the maximum of a signed 64-bit integer, which every supported database accepts.
+    ///
+    /// The SQL writers clamp the limit, the offset and the row number bounds to it, so a saturated offset such as `u64::MAX` still generates valid SQL which matches no rows.
+    pub const MAX_OFFSET: u64 = i64::MAX as u64;
+
+    /// Query `limit` items starting at `offset` of the `total_items` items from whichever end of the result set needs the smaller offset. `limit` must not be `0`.
+    pub(crate) const fn from_head_or_tail(offset: usize, limit: usize, total_items: usize) -> Self {
+        if offset >= total_items {
+            return Self {
+                reversed: false, limit: Some(limit), offset: offset as u64
+            };
+        }
+
+        let limit = if limit < total_items - offset { limit } else { total_items - offset };
+
+        let tail_offset = total_items - offset - limit;
+
+        if tail_offset < offset {
+            Self {
+                reversed: true, limit: Some(limit), offset: tail_offset as u64
+            }
+        } else {
+            Self {
+                reversed: false, limit: Some(limit), offset: offset as u64
+            }
+        }
+    }
+
+    /// Compute the limit and the offset from the Relay arguments `after`, `before`, `first` and `last`, whose cursors are offsets of items in the whole result set.
+    ///
+    /// The returned `PageQuery` is never reversed.
+    pub fn from_relay_arguments(
+        after: Option<usize>,
+        before: Option<usize>,
+        first: Option<usize>,
+        last: Option<usize>,
+        total_items: usize,
+    ) -> Self {
+        let mut end = before.map_or(total_items, |before| before.min(total_items));
+        let mut start = after.map_or(0, |after| after.saturating_add(1).min(end));
+
+        if let Some(first) = first {
+            end = end.min(start.saturating_add(first));
+        }
+
+        if let Some(last) = last {
+            start = start.max(end.saturating_sub(last));
+        }
+
+        Self {
+            reversed: false, limit: Some(end - start), offset: start as u64
+        }
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "sqlite", feature = "mssql", feature = "mssql2008"))]
+impl PageQuery {
+    #[inline]
+    const fn sql_limit(&self) -> Option<u64> {
+        match self.limit {
+            Some(limit) => Some(clamp_sql_value(limit as u64)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    const fn sql_offset(&self) -> u64 {
+        clamp_sql_value(self.offset)
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "sqlite", feature = "mssql", feature = "mssql2008"))]
+#[inline]
+const fn clamp_sql_value(value: u64) -> u64 {
+    if value > PageQuery::MAX_OFFSET { PageQuery::MAX_OFFSET } else { value }
+}
+
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+impl PageQuery {
+    fn write_sql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        use std::{fmt::Write, str::from_utf8_unchecked};
+
+        let len = s.len();
+
+        if let Some(limit) = self.sql_limit() {
+            s.write_fmt(format_args!("LIMIT {limit}")).unwrap();
+        }
+
+        let offset = self.sql_offset();
+
+        if offset > 0 {
+            if !s.is_empty() {
+                s.push(' ');
+            }
+
+            s.write_fmt(format_args!("OFFSET {offset}")).unwrap();
+        }
+
+        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl PageQuery {
+    /// Generate a `LIMIT` with `OFFSET` clause for MySQL.
+    ///
+    /// If `limit` is `Some(n)`,
+    ///
+    /// ```sql
+    /// LIMIT <limit> [OFFSET <offset>]
+    /// ```
+    ///
+    /// If `offset` is not zero,
+    ///
+    /// ```sql
+    /// [LIMIT <limit>] OFFSET <offset>
+    /// ```
+    ///
+    /// `limit` and `offset` are clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_mysql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.write_sql_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for MySQL which checks the row number generated by `SqlOrderByComponent::format_mysql_row_number`.
+    ///
+    /// If `limit` is `Some(n)`,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` <= <limit>
+    /// ```
+    ///
+    /// If `offset` is not zero,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` > <offset>
+    /// ```
+    ///
+    /// If both above are true,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` BETWEEN (<offset + 1>) AND <offset + limit>
+    /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_mysql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.write_row_number_filter(('`', '`'), row_number_column_name.as_ref(), s)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl PageQuery {
+    /// Generate a `LIMIT` with `OFFSET` clause for SQLite.
+    ///
+    /// If `limit` is `Some(n)`,
+    ///
+    /// ```sql
+    /// LIMIT <limit> [OFFSET <offset>]
+    /// ```
+    ///
+    /// If `offset` is not zero,
+    ///
+    /// ```sql
+    /// [LIMIT <limit>] OFFSET <offset>
+    /// ```
+    ///
+    /// `limit` and `offset` are clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_sqlite_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        self.write_sql_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for SQLite which checks the row number generated by `SqlOrderByComponent::format_sqlite_row_number`.
+    ///
+    /// If `limit` is `Some(n)`,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` <= <limit>
+    /// ```
+    ///
+    /// If `offset` is not zero,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` > <offset>
+    /// ```
+    ///
+    /// If both above are true,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` BETWEEN (<offset + 1>) AND <offset + limit>
+    /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_sqlite_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.write_row_number_filter(('`', '`'), row_number_column_name.as_ref(), s)
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl PageQuery {
+    /// Generate a `OFFSET` with `FETCH` clause for Microsoft SQL Server.
+    ///
+    /// If `limit` is `Some(n)` or `offset` is not zero,
+    ///
+    /// ```sql
+    /// OFFSET <offset> ROWS [FETCH NEXT <limit> ROWS ONLY]
+    /// ```
+    ///
+    /// `limit` and `offset` are clamped to `PageQuery::MAX_OFFSET`. SQL Server rejects `FETCH NEXT 0 ROWS`, so if `limit` is `Some(0)`, the offset is `PageQuery::MAX_OFFSET`, which no row can reach, and `FETCH` is omitted.
+    #[inline]
+    pub fn to_mssql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
+        use std::{fmt::Write, str::from_utf8_unchecked};
+
+        let len = s.len();
+
+        let offset = self.sql_offset();
+
+        if let Some(0) = self.sql_limit() {
+            s.write_fmt(format_args!("OFFSET {} ROWS", PageQuery::MAX_OFFSET)).unwrap();
+        } else if let Some(limit) = self.sql_limit() {
+            s.write_fmt(format_args!("OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY")).unwrap();
+        } else if offset > 0 {
+            s.write_fmt(format_args!("OFFSET {offset} ROWS")).unwrap();
+        }
+
+        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "sqlite", feature = "mssql", feature = "mssql2008"))]
+impl PageQuery {
+    fn write_row_number_filter<'a>(
+        &self,
+        quote: (char, char),
+        row_number_column_name: &str,
+        s: &'a mut String,
+    ) -> &'a str {
+        use std::{fmt::Write, str::from_utf8_unchecked};
+
+        let (open, close) = quote;
+
+        let len = s.len();
+
+        let offset = self.sql_offset();
+
+        if let Some(limit) = self.sql_limit() {
+            if offset > 0 {
+                // the bounds are clamped to `MAX_OFFSET`, which no row number can reach
+                s.write_fmt(format_args!(
+                    "WHERE {open}{row_number_column_name}{close} BETWEEN {} AND {}",
+                    clamp_sql_value(offset + 1),
+                    clamp_sql_value(offset + limit)
+                ))
+                .unwrap();
+            } else {
+                s.write_fmt(format_args!("WHERE {open}{row_number_column_name}{close} <= {limit}"))
+                    .unwrap();
+            }
+        } else if offset > 0 {
+            s.write_fmt(format_args!("WHERE {open}{row_number_column_name}{close} > {offset}"))
+                .unwrap();
+        }
+
+        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+    }
+}
+
+#[cfg(any(feature = "mssql", feature = "mssql2008"))]
+impl PageQuery {
+    /// Generate a `WHERE` clause for Microsoft SQL Server which checks the row number generated by `SqlOrderByComponent::format_mssql_row_number`.
+    ///
+    /// If `limit` is `Some(n)`,
+    ///
+    /// ```sql
+    /// WHERE [<row_number_column_name>] <= <limit>
+    /// ```
+    ///
+    /// If `offset` is not zero,
+    ///
+    /// ```sql
+    /// WHERE [<row_number_column_name>] > <offset>
+    /// ```
+    ///
+    /// If both above are true,
+    ///
+    /// ```sql
+    /// WHERE [<row_number_column_name>] BETWEEN (<offset + 1>) AND <offset + limit>
+    /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_mssql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.write_row_number_filter(('[', ']'), row_number_column_name.as_ref(), s)
+    }
+}
+
+#[cfg(feature = "mssql2008")]
+impl PageQuery {
+    /// Generate a `WHERE` clause for Microsoft SQL Server 2008 and earlier (used for check the row number).
+    ///
+    /// It is the same as `to_mssql_row_number_filter`.
+    #[inline]
+    pub fn to_mssql2008_limit_offset<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_mssql_row_number_filter(row_number_column_name, s)
+    }
+}
@@ -0,0 +1,131 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::Pagination;
+
+/// An item of a pager, created by `Pagination::page_window`.
+///
+/// With the `serde` feature, a page is serialized as `{ "page": <number> }`, and an ellipsis is serialized as `"ellipsis"`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PageItem {
+    /// A page number.
+    Page(usize),
+    /// Omitted pages.
+    Ellipsis,
+}
+
+impl Pagination {
+    /// Returns the page numbers to render in a pager, e.g. `1 … 4 5 [6] 7 8 … 20`.
+    ///
+    /// The window contains `boundaries` pages at both ends and `siblings` pages on each side of the current page. Omitted pages are replaced with `PageItem::Ellipsis`, unless only one page is omitted, in which case that page is shown.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{PageItem, Pagination};
+    /// #
+    /// let pagination =
+    ///     Pagination::new().items_per_page(10).total_items(200).page(6);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         PageItem::Page(1),
+    ///         PageItem::Ellipsis,
+    ///         PageItem::Page(4),
+    ///         PageItem::Page(5),
+    ///         PageItem::Page(6),
+    ///         PageItem::Page(7),
+    ///         PageItem::Page(8),
+    ///         PageItem::Ellipsis,
+    ///         PageItem::Page(20),
+    ///     ],
+    ///     pagination.page_window(2, 1)
+    /// );
+    /// ```
+    pub fn page_window(&self, siblings: usize, boundaries: usize) -> Vec<PageItem> {
+        let total_pages = self.get_total_pages();
+        let page = self.get_page();
+
+        let mut items = Vec::new();
+
+        if total_pages == 0 {
+            return items;
+        }
+
+        // the visible ranges: the first pages, the pages around the current page, and the last pages
+        let mut ranges = [
+            (1, boundaries.min(total_pages)),
+            (page.saturating_sub(siblings).max(1), page.saturating_add(siblings).min(total_pages)),
+            match boundaries.min(total_pages) {
+                0 => (1, 0),
+                boundaries => (total_pages - boundaries + 1, total_pages),
+            },
+        ];
+
+        ranges.sort_unstable();
+
+        // the last page which has been pushed or omitted
+        let mut last = 0;
+
+        let push_omitted = |items: &mut Vec<PageItem>, last: usize, omitted: usize| match omitted {
+            0 => (),
+            1 => items.push(PageItem::Page(last + 1)),
+            _ => items.push(PageItem::Ellipsis),
+        };
+
+        for (start, end) in ranges {
+            if start > end || end <= last {
+                continue;
+            }
+
+            let start = start.max(last + 1);
+
+            push_omitted(&mut items, last, start - last - 1);
+
+            items.extend((start..=end).map(PageItem::Page));
+
+            last = end;
+        }
+
+        push_omitted(&mut items, last, total_pages - last);
+
+        items
+    }
+
+    /// Returns the number of the next page, or `None` on the last page.
+    #[inline]
+    pub const fn next_page(&self) -> Option<usize> {
+        let page = self.get_page();
+
+        if page < self.get_total_pages() { Some(page + 1) } else { None }
+    }
+
+    /// Returns the number of the previous page, or `None` on the first page.
+    #[inline]
+    pub const fn prev_page(&self) -> Option<usize> {
+        let page = self.get_page();
+
+        if page > 1 { Some(page - 1) } else { None }
+    }
+
+    /// Returns the offsets of the items on the current page. The range is empty if there is no item.
+    #[inline]
+    pub fn offset_range(&self) -> Range<u64> {
+        let total_items = self.get_total_items() as u64;
+        let start = self.get_offset().min(total_items);
+
+        let end = match self.get_items_per_page() {
+            0 => total_items,
+            items_per_page => start.saturating_add(items_per_page as u64).min(total_items),
+        };
+
+        start..end
+    }
+
+    /// Returns the 1-based positions of the items on the current page, e.g. `101..=120` for "Showing 101–120 of 1,234". Returns `None` if there is no item.
+    #[inline]
+    pub fn item_range(&self) -> Option<RangeInclusive<usize>> {
+        let range = self.offset_range();
+
+        if range.is_empty() { None } else { Some(range.start as usize + 1..=range.end as usize) }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    AnyPaginationOptions, OffsetPaginationOptions, OrderByOptions, OutOfRangePolicy,
+    PageOutOfRangeError, PaginationOptions,
+};
+
 /// Struct representing pagination information.
 ///
 /// # Examples
@@ -11,15 +16,25 @@
 /// let total_pages = pagination.get_total_pages(); // 3
 /// ```
 ///
-/// With the `serde` feature, deserialization rejects values whose page or total page count is inconsistent.
+/// With the `serde` feature, deserialization rejects values whose total page count is inconsistent, and handles a page number which is out of range according to `out_of_range_policy`. If `out_of_range_policy` is absent, the deserialized value uses `Clamp`. A serialized `Pagination` with `Clamp` never has such a page number, so it is rejected with `Clamp`, whether the policy is given or not. `offset` is only serialized if it has been set, and `out_of_range_policy` is only serialized if it is not `Clamp`.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(try_from = "PaginationInput"))]
 pub struct Pagination {
-    page:           usize,
-    total_pages:    usize,
-    items_per_page: usize,
-    total_items:    usize,
+    page:                usize,
+    total_pages:         usize,
+    items_per_page:      usize,
+    total_items:         usize,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    offset:              Option<u64>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_clamp"))]
+    out_of_range_policy: OutOfRangePolicy,
+}
+
+#[cfg(feature = "serde")]
+#[inline]
+fn is_clamp(policy: &OutOfRangePolicy) -> bool {
+    *policy == OutOfRangePolicy::Clamp
 }
 
 impl Pagination {
@@ -36,12 +51,19 @@ impl Pagination {
     /// //     total_pages:    1,
     /// //     items_per_page: 0,
     /// //     total_items:    0,
+    /// //     offset:         None,
+    /// //     out_of_range_policy: OutOfRangePolicy::Clamp,
     /// // };
     /// ```
     #[inline]
     pub const fn new() -> Self {
         Self {
-            page: 1, total_pages: 1, items_per_page: 0, total_items: 0
+            page:                1,
+            total_pages:         1,
+            items_per_page:      0,
+            total_items:         0,
+            offset:              None,
+            out_of_range_policy: OutOfRangePolicy::Clamp,
         }
     }
 
@@ -49,14 +71,35 @@ impl Pagination {
     ///
     /// * If the value is `0`, it will be changed to `1`.
     /// * If `total_pages` is `0`, the value will be changed to `1`.
-    /// * If the value is bigger than `total_pages`, it will be changed to `total_pages`.
+    /// * If the value is bigger than `total_pages`, it will be changed to `total_pages`, unless the `OutOfRangePolicy` is not `Clamp`.
+    ///
+    /// The offset set by `at_offset` is cleared.
     #[inline]
     pub const fn page(mut self, page: usize) -> Self {
-        self.page = Self::normalize_page(page, self.total_pages);
+        self.offset = None;
+        self.page = self.out_of_range_policy.apply(page, self.total_pages);
 
         self
     }
 
+    /// Set what to do with a page number which is bigger than the total number of pages. Default: `OutOfRangePolicy::Clamp`.
+    ///
+    /// Set it before the page number, because a clamped page number cannot be restored.
+    #[inline]
+    pub const fn out_of_range_policy(mut self, out_of_range_policy: OutOfRangePolicy) -> Self {
+        self.out_of_range_policy = out_of_range_policy;
+
+        self.update_total_pages()
+    }
+
+    /// Set the offset of the first item, for clients which do not fetch items page by page. The page number becomes the number of the page which contains the first item, and `items_per_page` is the limit.
+    #[inline]
+    pub const fn at_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+
+        self.update_total_pages()
+    }
+
     /// Number of items per page.
     ///
     /// * If the value is `0`, it means **all items in a single page**.
@@ -78,28 +121,33 @@ impl Pagination {
     #[inline]
     const fn update_total_pages(mut self) -> Self {
         self.total_pages = Self::calculate_total_pages(self.items_per_page, self.total_items);
-        self.page = Self::normalize_page(self.page, self.total_pages);
+
+        if let Some(offset) = self.offset {
+            self.page = Self::offset_to_page(offset, self.items_per_page);
+        }
+
+        self.page = self.out_of_range_policy.apply(self.page, self.total_pages);
 
         self
     }
 
     #[inline]
-    const fn calculate_total_pages(items_per_page: usize, total_items: usize) -> usize {
-        match items_per_page {
-            0 => 1,
-            1 => total_items,
-            _ => total_items.div_ceil(items_per_page),
+    pub(crate) const fn offset_to_page(offset: u64, items_per_page: usize) -> usize {
+        if items_per_page == 0 {
+            1
+        } else {
+            let page = offset / items_per_page as u64;
+
+            if page >= usize::MAX as u64 { usize::MAX } else { page as usize + 1 }
         }
     }
 
     #[inline]
-    const fn normalize_page(page: usize, total_pages: usize) -> usize {
-        if page == 0 || total_pages == 0 {
-            1
-        } else if page > total_pages {
-            total_pages
-        } else {
-            page
+    pub(crate) const fn calculate_total_pages(items_per_page: usize, total_items: usize) -> usize {
+        match items_per_page {
+            0 => 1,
+            1 => total_items,
+            _ => total_items.div_ceil(items_per_page),
         }
     }
 }
@@ -107,10 +155,14 @@ impl Pagination {
 #[cfg(feature = "serde")]
 #[derive(serde::Deserialize)]
 struct PaginationInput {
-    page:           usize,
-    total_pages:    usize,
-    items_per_page: usize,
-    total_items:    usize,
+    page:                usize,
+    total_pages:         usize,
+    items_per_page:      usize,
+    total_items:         usize,
+    #[serde(default)]
+    offset:              Option<u64>,
+    #[serde(default)]
+    out_of_range_policy: Option<OutOfRangePolicy>,
 }
 
 #[cfg(feature = "serde")]
@@ -125,20 +177,77 @@ impl TryFrom<PaginationInput> for Pagination {
             return Err("total_pages does not match items_per_page and total_items");
         }
 
-        if value.page != Self::normalize_page(value.page, value.total_pages) {
+        let policy = value.out_of_range_policy.unwrap_or(OutOfRangePolicy::Clamp);
+
+        if value.page == 0 {
             return Err("page is outside the valid range");
         }
 
-        Ok(Self {
-            page:           value.page,
-            total_pages:    value.total_pages,
+        // with `Clamp`, whether it is given or not, the page number has to be the clamped one
+        if value.page != policy.apply(value.page, total_pages) {
+            return Err("page is outside the valid range");
+        }
+
+        if value.offset.is_some_and(|offset| offset > crate::PageQuery::MAX_OFFSET) {
+            return Err("offset is too large");
+        }
+
+        if let Some(offset) = value.offset
+            && policy.apply(value.page, total_pages)
+                != policy.apply(Self::offset_to_page(offset, value.items_per_page), total_pages)
+        {
+            return Err("page does not match offset");
+        }
+
+        let pagination = Self {
+            page: policy.apply(value.page, total_pages),
+            total_pages,
             items_per_page: value.items_per_page,
-            total_items:    value.total_items,
-        })
+            total_items: value.total_items,
+            offset: value.offset,
+            out_of_range_policy: policy,
+        };
+
+        if pagination.validate().is_err() {
+            return Err("page is outside the valid range");
+        }
+
+        Ok(pagination)
     }
 }
 
 impl Pagination {
+    /// Create options which request the current page (or the offset set by `at_offset`) again, e.g. for building links.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{AnyPaginationOptions, Pagination};
+    /// #
+    /// let pagination =
+    ///     Pagination::new().items_per_page(20).total_items(50).page(2);
+    ///
+    /// let AnyPaginationOptions::Page(options) = pagination.to_options(()) else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert_eq!(2, options.page);
+    /// assert_eq!(20, options.items_per_page);
+    /// ```
+    #[inline]
+    pub fn to_options<T: OrderByOptions>(&self, order_by: T) -> AnyPaginationOptions<T> {
+        match self.offset {
+            Some(offset) => AnyPaginationOptions::Offset(OffsetPaginationOptions {
+                offset,
+                limit: if self.items_per_page == 0 { None } else { Some(self.items_per_page) },
+                order_by,
+            }),
+            None => AnyPaginationOptions::Page(PaginationOptions {
+                page: self.page,
+                items_per_page: self.items_per_page,
+                order_by,
+            }),
+        }
+    }
+
     /// Get the page number.
     #[inline]
     pub const fn get_page(&self) -> usize {
@@ -164,4 +273,58 @@ impl Pagination {
     pub const fn get_total_items(&self) -> usize {
         self.total_items
     }
+
+    /// Get the offset of the first item on the current page, or the offset which has been set by `at_offset`.
+    #[inline]
+    pub const fn get_offset(&self) -> u64 {
+        match self.offset {
+            Some(offset) => offset,
+            None => (self.items_per_page as u64).saturating_mul(self.page as u64 - 1),
+        }
+    }
+
+    /// Returns whether the offset has been set by `at_offset`.
+    #[inline]
+    pub const fn is_offset_based(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    /// Get the `OutOfRangePolicy`.
+    #[inline]
+    pub const fn get_out_of_range_policy(&self) -> OutOfRangePolicy {
+        self.out_of_range_policy
+    }
+
+    /// Returns whether the page number is bigger than the total number of pages, which is only possible if the `OutOfRangePolicy` is not `Clamp`. An out-of-range page has no items.
+    #[inline]
+    pub const fn is_out_of_range(&self) -> bool {
+        let total_pages = if self.total_pages == 0 { 1 } else { self.total_pages };
+
+        self.page > total_pages
+    }
+
+    /// Returns `PageOutOfRangeError` if the page number is out of range and the `OutOfRangePolicy` is `Error`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{OutOfRangePolicy, Pagination};
+    /// #
+    /// let pagination = Pagination::new()
+    ///     .out_of_range_policy(OutOfRangePolicy::Error)
+    ///     .items_per_page(20)
+    ///     .total_items(50)
+    ///     .page(4);
+    ///
+    /// assert_eq!(4, pagination.get_page());
+    /// assert!(pagination.validate().is_err());
+    /// ```
+    #[inline]
+    pub const fn validate(&self) -> Result<(), PageOutOfRangeError> {
+        if matches!(self.out_of_range_policy, OutOfRangePolicy::Error) && self.is_out_of_range() {
+            Err(PageOutOfRangeError {
+                page: self.page, total_pages: self.total_pages
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
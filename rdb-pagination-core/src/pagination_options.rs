@@ -1,4 +1,7 @@
-use crate::OrderByOptions;
+use crate::{
+    OffsetError, OffsetPaginationOptions, OrderByOptions, PageOutOfRangeError, PageQuery,
+    Pagination,
+};
 
 /// Struct representing pagination options.
 ///
@@ -10,15 +13,17 @@ use crate::OrderByOptions;
 /// let options = PaginationOptions::new().page(1).items_per_page(20);
 /// ```
 ///
-/// With the `serde` feature, this type implements each serde trait when `T` implements the same trait.
+/// For an arbitrary offset, use `OffsetPaginationOptions`, or `AnyPaginationOptions` to accept either form.
+///
+/// With the `serde` feature, this type implements each serde trait when `T` implements the same trait. Every field is optional when deserializing, and `order_by` is checked by `OrderByOptions::validate`.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
+#[cfg_attr(feature = "serde", serde(try_from = "PaginationOptionsInput<T>"))]
 pub struct PaginationOptions<T: OrderByOptions = ()> {
     /// Page number.
     ///
     /// * If the value is `0`, it will default to `1`.
-    /// * If the value exceeds the maximum page number, it will be considered as the maximum page number.
+    /// * If the value exceeds the maximum page number, it is handled according to `T::OUT_OF_RANGE_POLICY`. By default (`OutOfRangePolicy::Clamp`), it will be considered as the maximum page number.
     ///
     ///  Default: `1`.
     pub page:           usize,
@@ -34,6 +39,35 @@ pub struct PaginationOptions<T: OrderByOptions = ()> {
     pub order_by:       T,
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct PaginationOptionsInput<T> {
+    #[serde(default)]
+    page:           Option<usize>,
+    #[serde(default)]
+    items_per_page: Option<usize>,
+    #[serde(default)]
+    order_by:       T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: OrderByOptions> TryFrom<PaginationOptionsInput<T>> for PaginationOptions<T> {
+    type Error = std::borrow::Cow<'static, str>;
+
+    #[inline]
+    fn try_from(value: PaginationOptionsInput<T>) -> Result<Self, Self::Error> {
+        if let Err(error) = value.order_by.validate() {
+            return Err(error.to_string().into());
+        }
+
+        Ok(Self {
+            page:           value.page.unwrap_or(1),
+            items_per_page: value.items_per_page.unwrap_or(0),
+            order_by:       value.order_by,
+        })
+    }
+}
+
 impl PaginationOptions {
     /// Create a new `PaginationOptions`.
     ///
@@ -82,7 +116,7 @@ impl<T: OrderByOptions> PaginationOptions<T> {
     /// Set the page number.
     ///
     /// * If the value is `0`, it will be considered as `1`.
-    /// * If the value exceeds the maximum page number, it will be considered as the maximum page number.
+    /// * If the value exceeds the maximum page number, it is handled according to `T::OUT_OF_RANGE_POLICY`. By default (`OutOfRangePolicy::Clamp`), it will be considered as the maximum page number.
     #[inline]
     pub const fn page(mut self, page: usize) -> Self {
         self.page = page;
@@ -100,6 +134,25 @@ impl<T: OrderByOptions> PaginationOptions<T> {
         self
     }
 
+    /// Convert to `OffsetPaginationOptions` which start at the offset of the first item instead of the page number. `items_per_page` becomes the limit.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::PaginationOptions;
+    /// #
+    /// let options = PaginationOptions::new().items_per_page(20).at_offset(35);
+    ///
+    /// assert_eq!(35, options.offset);
+    /// assert_eq!(Some(20), options.limit);
+    /// ```
+    #[inline]
+    pub fn at_offset(self, offset: u64) -> OffsetPaginationOptions<T> {
+        OffsetPaginationOptions {
+            offset,
+            limit: self.limit(),
+            order_by: self.order_by,
+        }
+    }
+
     /// Set the ordering options which has to implement the `OrderByOptions` trait.
     #[inline]
     pub fn order_by(mut self, order_by: T) -> Self {
@@ -109,49 +162,167 @@ impl<T: OrderByOptions> PaginationOptions<T> {
     }
 
     /// Compute the offset for pagination.
+    ///
+    /// If the offset does not fit in `u64`, it saturates at `u64::MAX`. Use `try_offset` to reject such values. The SQL writers clamp a saturated offset to `PageQuery::MAX_OFFSET`, so the generated clause is still valid and matches no rows.
     #[inline]
     pub const fn offset(&self) -> u64 {
+        match self.try_offset() {
+            Ok(offset) => offset,
+            Err(_) => u64::MAX,
+        }
+    }
+
+    /// Compute the offset for pagination, returning `OffsetError::Overflow` if it does not fit in `u64`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{OffsetError, PaginationOptions};
+    /// #
+    /// let options =
+    ///     PaginationOptions::new().page(usize::MAX).items_per_page(usize::MAX);
+    ///
+    /// assert_eq!(Err(OffsetError::Overflow), options.try_offset());
+    /// ```
+    #[inline]
+    pub const fn try_offset(&self) -> Result<u64, OffsetError> {
         if self.items_per_page == 0 {
-            0
+            Ok(0)
         } else {
             match self.page {
-                0 | 1 => 0,
-                _ => (self.items_per_page * (self.page - 1)) as u64,
+                0 | 1 => Ok(0),
+                _ => match (self.items_per_page as u64).checked_mul((self.page - 1) as u64) {
+                    Some(offset) => Ok(offset),
+                    None => Err(OffsetError::Overflow),
+                },
             }
         }
     }
 
+    /// Compute the offset for pagination like `try_offset`, and also return `OffsetError::PageTooLarge` if the page number is bigger than `max_page`, so that deep paging can be rejected before querying the database.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{OffsetError, PaginationOptions};
+    /// #
+    /// let options = PaginationOptions::new().page(10000000).items_per_page(20);
+    ///
+    /// assert_eq!(
+    ///     Err(OffsetError::PageTooLarge {
+    ///         page: 10000000, max_page: 500
+    ///     }),
+    ///     options.try_offset_with_max_page(500)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_offset_with_max_page(&self, max_page: usize) -> Result<u64, OffsetError> {
+        if self.page > max_page {
+            return Err(OffsetError::PageTooLarge {
+                page: self.page,
+                max_page,
+            });
+        }
+
+        self.try_offset()
+    }
+
     /// Compute the limit for pagination. `None` means **unlimited**.
     #[inline]
     pub const fn limit(&self) -> Option<usize> {
         if self.items_per_page == 0 { None } else { Some(self.items_per_page) }
     }
-}
 
-#[cfg(any(feature = "mysql", feature = "sqlite"))]
-impl<T: OrderByOptions> PaginationOptions<T> {
-    fn to_sql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
-        use std::{fmt::Write, str::from_utf8_unchecked};
+    #[inline]
+    pub(crate) const fn to_page_query(&self) -> PageQuery {
+        PageQuery {
+            reversed: false, limit: self.limit(), offset: self.offset()
+        }
+    }
 
-        let len = s.len();
+    /// Create a `Pagination` for the requested page with the known total number of items.
+    ///
+    /// The page number is handled according to `T::OUT_OF_RANGE_POLICY`, which is `OutOfRangePolicy::Clamp` by default, so that the last page is served instead. Use `is_out_of_range` to detect that.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::PaginationOptions;
+    /// #
+    /// let options = PaginationOptions::new().page(2).items_per_page(20);
+    ///
+    /// let pagination = options.to_pagination(50);
+    ///
+    /// assert_eq!(2, pagination.get_page());
+    /// assert_eq!(3, pagination.get_total_pages());
+    /// ```
+    #[inline]
+    pub const fn to_pagination(&self, total_items: usize) -> Pagination {
+        Pagination::new()
+            .out_of_range_policy(T::OUT_OF_RANGE_POLICY)
+            .items_per_page(self.items_per_page)
+            .total_items(total_items)
+            .page(self.page)
+    }
 
-        let limit = self.limit();
+    /// Like `to_pagination`, but returns `PageOutOfRangeError` if the requested page is out of range and `T::OUT_OF_RANGE_POLICY` is `OutOfRangePolicy::Error`.
+    #[inline]
+    pub const fn try_to_pagination(
+        &self,
+        total_items: usize,
+    ) -> Result<Pagination, PageOutOfRangeError> {
+        let pagination = self.to_pagination(total_items);
 
-        if let Some(limit) = limit {
-            s.write_fmt(format_args!("LIMIT {limit}")).unwrap();
+        match pagination.validate() {
+            Ok(()) => Ok(pagination),
+            Err(error) => Err(error),
         }
+    }
 
-        let offset = self.offset();
+    /// Returns whether the requested page is after the last page, so that `to_pagination` and `page_query` would handle it according to `T::OUT_OF_RANGE_POLICY`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::PaginationOptions;
+    /// #
+    /// let options = PaginationOptions::new().page(4).items_per_page(20);
+    ///
+    /// assert!(options.is_out_of_range(50));
+    /// assert!(!options.is_out_of_range(61));
+    /// ```
+    #[inline]
+    pub const fn is_out_of_range(&self, total_items: usize) -> bool {
+        let total_pages = Pagination::calculate_total_pages(self.items_per_page, total_items);
 
-        if offset > 0 {
-            if !s.is_empty() {
-                s.push(' ');
-            }
+        self.page > 1 && self.page > total_pages
+    }
+
+    /// Compute the limit and the offset for the page with the known total number of items, querying from whichever end of the result set needs the smaller offset.
+    ///
+    /// The page number is clamped like `Pagination` does if `T::OUT_OF_RANGE_POLICY` is `OutOfRangePolicy::Clamp`. For a page near the end, the returned `PageQuery` is reversed: the `ORDER BY` clause has to be reversed, and the fetched rows have to be reversed again in memory.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{PageQuery, PaginationOptions};
+    /// #
+    /// let options = PaginationOptions::new().page(49).items_per_page(20);
+    ///
+    /// assert_eq!(
+    ///     PageQuery {
+    ///         reversed: true, limit: Some(20), offset: 20
+    ///     },
+    ///     options.page_query(1000)
+    /// );
+    /// ```
+    pub const fn page_query(&self, total_items: usize) -> PageQuery {
+        if self.items_per_page == 0 {
+            return self.to_page_query();
+        }
 
-            s.write_fmt(format_args!("OFFSET {offset}")).unwrap();
+        let total_pages = Pagination::calculate_total_pages(self.items_per_page, total_items);
+        let page = T::OUT_OF_RANGE_POLICY.apply(self.page, total_pages);
+
+        if page > 1 && page > total_pages {
+            return self.to_page_query();
         }
 
-        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+        PageQuery::from_head_or_tail(
+            self.items_per_page * (page - 1),
+            self.items_per_page,
+            total_items,
+        )
     }
 }
 
@@ -170,9 +341,41 @@ impl<T: OrderByOptions> PaginationOptions<T> {
     /// ```sql
     /// [LIMIT <limit()>] OFFSET <offset()>
     /// ```
+    ///
+    /// `limit()` and `offset()` are clamped to `PageQuery::MAX_OFFSET`.
     #[inline]
     pub fn to_mysql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
-        self.to_sql_limit_offset(s)
+        self.to_page_query().to_mysql_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for MySQL which checks the row number generated by `SqlOrderByComponent::format_mysql_row_number`.
+    ///
+    /// If `limit()` is `Some(n)`,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` <= <limit()>
+    /// ```
+    ///
+    /// If `offset()` is not zero,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` > <offset()>
+    /// ```
+    ///
+    /// If both above are true,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` BETWEEN (<offset() + 1>) AND <offset() + limit()>
+    /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_mysql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mysql_row_number_filter(row_number_column_name, s)
     }
 }
 
@@ -191,9 +394,41 @@ impl<T: OrderByOptions> PaginationOptions<T> {
     /// ```sql
     /// [LIMIT <limit()>] OFFSET <offset()>
     /// ```
+    ///
+    /// `limit()` and `offset()` are clamped to `PageQuery::MAX_OFFSET`.
     #[inline]
     pub fn to_sqlite_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
-        self.to_sql_limit_offset(s)
+        self.to_page_query().to_sqlite_limit_offset(s)
+    }
+
+    /// Generate a `WHERE` clause for SQLite which checks the row number generated by `SqlOrderByComponent::format_sqlite_row_number`.
+    ///
+    /// If `limit()` is `Some(n)`,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` <= <limit()>
+    /// ```
+    ///
+    /// If `offset()` is not zero,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` > <offset()>
+    /// ```
+    ///
+    /// If both above are true,
+    ///
+    /// ```sql
+    /// WHERE `<row_number_column_name>` BETWEEN (<offset() + 1>) AND <offset() + limit()>
+    /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_sqlite_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_sqlite_row_number_filter(row_number_column_name, s)
     }
 }
 
@@ -206,23 +441,44 @@ impl<T: OrderByOptions> PaginationOptions<T> {
     /// ```sql
     /// OFFSET <offset()> ROWS [FETCH NEXT <limit()> ROWS ONLY]
     /// ```
+    ///
+    /// `limit()` and `offset()` are clamped to `PageQuery::MAX_OFFSET`.
     #[inline]
     pub fn to_mssql_limit_offset<'a>(&self, s: &'a mut String) -> &'a str {
-        use std::{fmt::Write, str::from_utf8_unchecked};
-
-        let len = s.len();
-
-        let limit = self.limit();
-
-        let offset = self.offset();
-
-        if let Some(limit) = limit {
-            s.write_fmt(format_args!("OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY")).unwrap();
-        } else if offset > 0 {
-            s.write_fmt(format_args!("OFFSET {offset} ROWS")).unwrap();
-        }
+        self.to_page_query().to_mssql_limit_offset(s)
+    }
+}
 
-        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+#[cfg(any(feature = "mssql", feature = "mssql2008"))]
+impl<T: OrderByOptions> PaginationOptions<T> {
+    /// Generate a `WHERE` clause for Microsoft SQL Server which checks the row number generated by `SqlOrderByComponent::format_mssql_row_number`.
+    ///
+    /// If `limit()` is `Some(n)`,
+    ///
+    /// ```sql
+    /// WHERE [<row_number_column_name>] <= <limit()>
+    /// ```
+    ///
+    /// If `offset()` is not zero,
+    ///
+    /// ```sql
+    /// WHERE [<row_number_column_name>] > <offset()>
+    /// ```
+    ///
+    /// If both above are true,
+    ///
+    /// ```sql
+    /// WHERE [<row_number_column_name>] BETWEEN (<offset() + 1>) AND <offset() + limit()>
+    /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
+    #[inline]
+    pub fn to_mssql_row_number_filter<'a>(
+        &self,
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        self.to_page_query().to_mssql_row_number_filter(row_number_column_name, s)
     }
 }
 
@@ -247,35 +503,14 @@ impl<T: OrderByOptions> PaginationOptions<T> {
     /// ```sql
     /// WHERE [<row_number_column_name>] BETWEEN (<offset() + 1>) AND <offset() + limit()>
     /// ```
+    ///
+    /// Every bound is clamped to `PageQuery::MAX_OFFSET`.
     #[inline]
     pub fn to_mssql2008_limit_offset<'a>(
         &self,
         row_number_column_name: impl AsRef<str>,
         s: &'a mut String,
     ) -> &'a str {
-        use std::{fmt::Write, str::from_utf8_unchecked};
-
-        let row_number_column_name = row_number_column_name.as_ref();
-
-        let len = s.len();
-
-        let offset = self.offset();
-
-        if let Some(limit) = self.limit() {
-            if offset > 0 {
-                s.write_fmt(format_args!(
-                    "WHERE [{row_number_column_name}] BETWEEN {} AND {}",
-                    offset + 1,
-                    offset + limit as u64
-                ))
-                .unwrap();
-            } else {
-                s.write_fmt(format_args!("WHERE [{row_number_column_name}] <= {limit}")).unwrap();
-            }
-        } else if offset > 0 {
-            s.write_fmt(format_args!("WHERE [{row_number_column_name}] > {offset}")).unwrap();
-        }
-
-        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+        self.to_page_query().to_mssql2008_limit_offset(row_number_column_name, s)
     }
 }
@@ -20,6 +20,8 @@ pub struct TableColumnAttributes {
 #[derive(Debug, Clone)]
 pub struct Relationship {
     pub(crate) table_name:   TableName,
+    /// The alias of the base table in the `FROM` clause, which is rendered instead of `table_name`.
+    pub(crate) alias:        Option<TableName>,
     pub(crate) relationship: HashMap<TableName, TableColumnAttributes>,
 }
 
@@ -28,10 +30,17 @@ impl Relationship {
     pub fn new(table_name: TableName) -> Self {
         Self {
             table_name,
+            alias: None,
             relationship: HashMap::new(),
         }
     }
 
+    /// Set the alias of the base table, e.g. `c` for `FROM component AS c`. Every `SqlJoin` and `SqlOrderByComponent` which points at the base table is rendered with the alias.
+    #[inline]
+    pub fn set_alias(&mut self, alias: TableName) {
+        self.alias = Some(alias);
+    }
+
     #[inline]
     pub fn join_check(
         &mut self,
@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use utoipa::{
+    Modify, ToSchema,
+    openapi::{OpenApi, RefOr, Schema},
+};
+
+use crate::OrderByOptions;
+
+/// A `utoipa::Modify` which lists `OrderByOptions::REQUIRED_CAPABILITIES` of `T` in its OpenAPI schema, as the `x-required-capabilities` extension whose value is an object mapping each field to its capability. Put it in a constant to use it in `#[openapi(modifiers(&CONSTANT))]`.
+#[derive(Debug)]
+pub struct RequiredCapabilities<T>(PhantomData<T>);
+
+impl<T> RequiredCapabilities<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for RequiredCapabilities<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: OrderByOptions + ToSchema> Modify for RequiredCapabilities<T> {
+    fn modify(&self, openapi: &mut OpenApi) {
+        if T::REQUIRED_CAPABILITIES.is_empty() {
+            return;
+        }
+
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+
+        if let Some(RefOr::T(Schema::Object(object))) =
+            components.schemas.get_mut(T::name().as_ref())
+        {
+            let capabilities = T::REQUIRED_CAPABILITIES
+                .iter()
+                .map(|(field, capability)| {
+                    (field.to_string(), serde_json::Value::from(*capability))
+                })
+                .collect();
+
+            object.extensions.get_or_insert_with(Default::default).insert(
+                String::from("x-required-capabilities"),
+                serde_json::Value::Object(capabilities),
+            );
+        }
+    }
+}
@@ -1,22 +1,70 @@
 use std::{
+    collections::HashMap,
     error::Error,
     fmt,
     fmt::{Display, Formatter},
 };
 
-use crate::{ColumnName, TableColumnAttributes, TableName};
+use crate::{ColumnName, Name, SqlOrderByComponent, TableColumn, TableColumnAttributes, TableName};
 
 /// Struct for generating the `JOIN` clause.
+///
+/// It is non-exhaustive, so that adding a member is not a breaking change. Create it with `SqlJoin::new` and set the optional members with the builder-style setters.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct SqlJoin {
     pub other_table_name:  TableName,
     pub other_column_name: ColumnName,
     pub real_table_name:   Option<TableName>,
+    /// The schema (or database) which the joined table belongs to. It can be qualified by a catalog, e.g. `catalog.schema`. If it exists, the joined table is always aliased as `other_table_name`.
+    pub schema_name:       Option<TableName>,
     pub using_table_name:  TableName,
     pub using_column_name: ColumnName,
 }
 
 impl SqlJoin {
+    /// Create a `SqlJoin` which joins the `other` table on `other.column = using.column`.
+    ///
+    /// ```rust
+    /// # use rdb_pagination_core::{Name, SqlJoin};
+    /// #
+    /// let join = SqlJoin::new(
+    ///     (Name::Static("vendor"), Name::Static("id")),
+    ///     (Name::Static("component"), Name::Static("vendor_id")),
+    /// )
+    /// .real_table_name(Name::Static("company"))
+    /// .schema_name(Name::Static("shared"));
+    ///
+    /// assert_eq!(Some(Name::Static("shared")), join.schema_name);
+    /// ```
+    #[inline]
+    pub fn new(other: TableColumn, using: TableColumn) -> Self {
+        Self {
+            other_table_name:  other.0,
+            other_column_name: other.1,
+            real_table_name:   None,
+            schema_name:       None,
+            using_table_name:  using.0,
+            using_column_name: using.1,
+        }
+    }
+
+    /// Set the real name of the joined table, which is aliased as `other_table_name`.
+    #[inline]
+    pub fn real_table_name(mut self, real_table_name: TableName) -> Self {
+        self.real_table_name = Some(real_table_name);
+
+        self
+    }
+
+    /// Set the schema (or database) which the joined table belongs to.
+    #[inline]
+    pub fn schema_name(mut self, schema_name: TableName) -> Self {
+        self.schema_name = Some(schema_name);
+
+        self
+    }
+
     #[doc(hidden)]
     #[inline]
     pub fn from_table_column_attributes(table_column_attributes: &TableColumnAttributes) -> Self {
@@ -24,20 +72,53 @@ impl SqlJoin {
             other_table_name:  table_column_attributes.table_name.clone(),
             other_column_name: table_column_attributes.column_name.clone(),
             real_table_name:   table_column_attributes.real_table_name.clone(),
+            schema_name:       None,
             using_table_name:  table_column_attributes.foreign_table_name.clone(),
             using_column_name: table_column_attributes.foreign_column_name.clone(),
         }
     }
+
+    /// Whether the two `SqlJoin`s join the same table on the same columns, regardless of their aliases.
+    #[inline]
+    fn is_equivalent(&self, other: &SqlJoin) -> bool {
+        self.real_table_name.as_ref().unwrap_or(&self.other_table_name)
+            == other.real_table_name.as_ref().unwrap_or(&other.other_table_name)
+            && self.schema_name == other.schema_name
+            && self.other_column_name == other.other_column_name
+            && self.using_table_name == other.using_table_name
+            && self.using_column_name == other.using_column_name
+    }
 }
 
 #[cfg(any(feature = "mysql", feature = "sqlite"))]
 impl SqlJoin {
-    fn to_sql_join_clause<'a>(&self, s: &'a mut String) -> &'a str {
+    fn to_sql_join_clause<'a>(&self, schema_name: Option<&str>, s: &'a mut String) -> &'a str {
         use std::{fmt::Write, str::from_utf8_unchecked};
 
         let len = s.len();
 
-        if let Some(real_table_name) = &self.real_table_name {
+        if let Some(schema_name) = schema_name {
+            s.push_str("LEFT JOIN ");
+
+            // the schema name may come from `qualify` at runtime, so its quotes are escaped
+            for name in schema_name.split('.') {
+                s.push('`');
+                s.push_str(&name.replace('`', "``"));
+                s.push_str("`.");
+            }
+
+            s.write_fmt(format_args!(
+                "`{real_table_name}` AS `{other_table_name}` ON \
+                 `{other_table_name}`.`{other_column_name}` = \
+                 `{using_table_name}`.`{using_column_name}`",
+                real_table_name = self.real_table_name.as_ref().unwrap_or(&self.other_table_name),
+                other_table_name = self.other_table_name,
+                other_column_name = self.other_column_name,
+                using_table_name = self.using_table_name,
+                using_column_name = self.using_column_name,
+            ))
+            .unwrap()
+        } else if let Some(real_table_name) = &self.real_table_name {
             s.write_fmt(format_args!(
                 "LEFT JOIN `{real_table_name}` AS `{other_table_name}` ON \
                  `{other_table_name}`.`{other_column_name}` = \
@@ -63,7 +144,11 @@ impl SqlJoin {
         unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
     }
 
-    fn format_sql_join_clauses<'a>(joins: &[SqlJoin], s: &'a mut String) -> &'a str {
+    fn format_sql_join_clauses<'a>(
+        joins: &[SqlJoin],
+        qualify: impl Fn(&SqlJoin) -> Option<TableName>,
+        s: &'a mut String,
+    ) -> &'a str {
         use std::str::from_utf8_unchecked;
 
         if joins.is_empty() {
@@ -73,7 +158,9 @@ impl SqlJoin {
         let len = s.len();
 
         for join in joins {
-            join.to_sql_join_clause(s);
+            let schema_name = join.schema_name.clone().or_else(|| qualify(join));
+
+            join.to_sql_join_clause(schema_name.as_deref(), s);
             s.push('\n');
         }
 
@@ -102,9 +189,17 @@ impl SqlJoin {
     /// ```sql
     /// JOIN `<other_table_name>` ON `<other_table_name>`.`<other_column_name>` = `<using_table_name>`.`<using_column_name>`
     /// ```
+    ///
+    /// If `schema_name` exists,
+    ///
+    /// ```sql
+    /// JOIN `<schema_name>`.`<real_table_name>` AS `<other_table_name>` ON `<other_table_name>`.`<other_column_name>` = `<using_table_name>`.`<using_column_name>`
+    /// ```
+    ///
+    /// where `real_table_name` falls back to `other_table_name`.
     #[inline]
     pub fn to_mysql_join_clause<'a>(&self, s: &'a mut String) -> &'a str {
-        self.to_sql_join_clause(s)
+        self.to_sql_join_clause(self.schema_name.as_deref(), s)
     }
 
     /// Generate `JOIN` clauses for MySQL.
@@ -112,7 +207,23 @@ impl SqlJoin {
     /// Concatenate a series of `SqlJoin`s with `\n`.
     #[inline]
     pub fn format_mysql_join_clauses<'a>(joins: &[SqlJoin], s: &'a mut String) -> &'a str {
-        Self::format_sql_join_clauses(joins, s)
+        Self::format_sql_join_clauses(joins, |_| None, s)
+    }
+
+    /// Generate `JOIN` clauses for MySQL, where the joined tables without `schema_name` are qualified by the schema (or database) returned from `qualify`, e.g. `tenant_42` for the tenant of the current request.
+    ///
+    /// ```sql
+    /// JOIN `<schema_name>`.`<real_table_name>` AS `<other_table_name>` ON `<other_table_name>`.`<other_column_name>` = `<using_table_name>`.`<using_column_name>`
+    /// ```
+    ///
+    /// The schema name is split by `.`, so that a catalog can be included, and the quote characters in it are escaped.
+    #[inline]
+    pub fn format_mysql_join_clauses_with<'a>(
+        joins: &[SqlJoin],
+        qualify: impl Fn(&SqlJoin) -> Option<TableName>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_join_clauses(joins, qualify, s)
     }
 }
 
@@ -131,9 +242,17 @@ impl SqlJoin {
     /// ```sql
     /// JOIN `<other_table_name>` ON `<other_table_name>`.`<other_column_name>` = `<using_table_name>`.`<using_column_name>`
     /// ```
+    ///
+    /// If `schema_name` exists,
+    ///
+    /// ```sql
+    /// JOIN `<schema_name>`.`<real_table_name>` AS `<other_table_name>` ON `<other_table_name>`.`<other_column_name>` = `<using_table_name>`.`<using_column_name>`
+    /// ```
+    ///
+    /// where `real_table_name` falls back to `other_table_name`.
     #[inline]
     pub fn to_sqlite_join_clause<'a>(&self, s: &'a mut String) -> &'a str {
-        self.to_sql_join_clause(s)
+        self.to_sql_join_clause(self.schema_name.as_deref(), s)
     }
 
     /// Generate `JOIN` clauses for SQLite.
@@ -141,18 +260,55 @@ impl SqlJoin {
     /// Concatenate a series of `SqlJoin`s with `\n`.
     #[inline]
     pub fn format_sqlite_join_clauses<'a>(joins: &[SqlJoin], s: &'a mut String) -> &'a str {
-        Self::format_sql_join_clauses(joins, s)
+        Self::format_sql_join_clauses(joins, |_| None, s)
+    }
+
+    /// Generate `JOIN` clauses for SQLite, where the joined tables without `schema_name` are qualified by the schema (or database) returned from `qualify`, e.g. `tenant_42` for the tenant of the current request.
+    ///
+    /// ```sql
+    /// JOIN `<schema_name>`.`<real_table_name>` AS `<other_table_name>` ON `<other_table_name>`.`<other_column_name>` = `<using_table_name>`.`<using_column_name>`
+    /// ```
+    ///
+    /// The schema name is split by `.`, so that a catalog can be included, and the quote characters in it are escaped.
+    #[inline]
+    pub fn format_sqlite_join_clauses_with<'a>(
+        joins: &[SqlJoin],
+        qualify: impl Fn(&SqlJoin) -> Option<TableName>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_join_clauses(joins, qualify, s)
     }
 }
 
 #[cfg(any(feature = "mssql", feature = "mssql2008"))]
 impl SqlJoin {
-    fn to_sql_join_clause_ms<'a>(&self, s: &'a mut String) -> &'a str {
+    fn to_sql_join_clause_ms<'a>(&self, schema_name: Option<&str>, s: &'a mut String) -> &'a str {
         use std::{fmt::Write, str::from_utf8_unchecked};
 
         let len = s.len();
 
-        if let Some(real_table_name) = &self.real_table_name {
+        if let Some(schema_name) = schema_name {
+            s.push_str("LEFT JOIN ");
+
+            // the schema name may come from `qualify` at runtime, so its quotes are escaped
+            for name in schema_name.split('.') {
+                s.push('[');
+                s.push_str(&name.replace(']', "]]"));
+                s.push_str("].");
+            }
+
+            s.write_fmt(format_args!(
+                "[{real_table_name}] AS [{other_table_name}] ON \
+                 [{other_table_name}].[{other_column_name}] = \
+                 [{using_table_name}].[{using_column_name}]",
+                real_table_name = self.real_table_name.as_ref().unwrap_or(&self.other_table_name),
+                other_table_name = self.other_table_name,
+                other_column_name = self.other_column_name,
+                using_table_name = self.using_table_name,
+                using_column_name = self.using_column_name,
+            ))
+            .unwrap()
+        } else if let Some(real_table_name) = &self.real_table_name {
             s.write_fmt(format_args!(
                 "LEFT JOIN [{real_table_name}] AS [{other_table_name}] ON \
                  [{other_table_name}].[{other_column_name}] = \
@@ -178,7 +334,11 @@ impl SqlJoin {
         unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
     }
 
-    fn format_sql_join_clauses_ms<'a>(joins: &[SqlJoin], s: &'a mut String) -> &'a str {
+    fn format_sql_join_clauses_ms<'a>(
+        joins: &[SqlJoin],
+        qualify: impl Fn(&SqlJoin) -> Option<TableName>,
+        s: &'a mut String,
+    ) -> &'a str {
         use std::str::from_utf8_unchecked;
 
         if joins.is_empty() {
@@ -188,7 +348,9 @@ impl SqlJoin {
         let len = s.len();
 
         for join in joins {
-            join.to_sql_join_clause_ms(s);
+            let schema_name = join.schema_name.clone().or_else(|| qualify(join));
+
+            join.to_sql_join_clause_ms(schema_name.as_deref(), s);
             s.push('\n');
         }
 
@@ -217,9 +379,17 @@ impl SqlJoin {
     /// ```sql
     /// JOIN [<other_table_name>] ON [<other_table_name>].[<other_column_name>] = [<using_table_name>].[<using_column_name>]
     /// ```
+    ///
+    /// If `schema_name` exists,
+    ///
+    /// ```sql
+    /// JOIN [<schema_name>].[<real_table_name>] AS [<other_table_name>] ON [<other_table_name>].[<other_column_name>] = [<using_table_name>].[<using_column_name>]
+    /// ```
+    ///
+    /// where `real_table_name` falls back to `other_table_name`.
     #[inline]
     pub fn to_mssql_join_clause<'a>(&self, s: &'a mut String) -> &'a str {
-        self.to_sql_join_clause_ms(s)
+        self.to_sql_join_clause_ms(self.schema_name.as_deref(), s)
     }
 
     /// Generate `JOIN` clauses for Microsoft SQL Server.
@@ -227,7 +397,23 @@ impl SqlJoin {
     /// Concatenate a series of `SqlJoin`s with `\n`.
     #[inline]
     pub fn format_mssql_join_clauses<'a>(joins: &[SqlJoin], s: &'a mut String) -> &'a str {
-        Self::format_sql_join_clauses_ms(joins, s)
+        Self::format_sql_join_clauses_ms(joins, |_| None, s)
+    }
+
+    /// Generate `JOIN` clauses for Microsoft SQL Server, where the joined tables without `schema_name` are qualified by the schema (or database) returned from `qualify`, e.g. `tenant_42` for the tenant of the current request.
+    ///
+    /// ```sql
+    /// JOIN [<schema_name>].[<real_table_name>] AS [<other_table_name>] ON [<other_table_name>].[<other_column_name>] = [<using_table_name>].[<using_column_name>]
+    /// ```
+    ///
+    /// The schema name is split by `.`, so that a catalog can be included, and the quote characters in it are escaped.
+    #[inline]
+    pub fn format_mssql_join_clauses_with<'a>(
+        joins: &[SqlJoin],
+        qualify: impl Fn(&SqlJoin) -> Option<TableName>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_join_clauses_ms(joins, qualify, s)
     }
 }
 
@@ -235,6 +421,23 @@ impl SqlJoin {
 pub trait SqlJoinsOps {
     /// Insert a `SqlJoin` if it does not exist. Return `Ok(true)` if a new `SqlJoin` has been pushed.
     fn add_join(&mut self, join: SqlJoin) -> Result<bool, SqlJoinsInsertError>;
+
+    /// Alias every joined table as `prefix` followed by its name, e.g. `__ob_component_type`, so that the joins do not collide with the tables of a larger query. The references in `order_by_components` are renamed accordingly. The other tables, i.e. the base table, are renamed to `base_table_name` if it is `Some`.
+    fn prefix_aliases(
+        &mut self,
+        prefix: &str,
+        base_table_name: Option<TableName>,
+        order_by_components: &mut [SqlOrderByComponent],
+    );
+
+    /// Merge `joins` created for `order_by_components` into the joins which a larger query already has. A join which is equivalent to an existing one, i.e. joins the same table on the same columns, is not added, and the references in `order_by_components` are renamed to the alias of the existing join. The other joins are added by `add_join`.
+    ///
+    /// If an error occurs, nothing is changed.
+    fn merge_joins(
+        &mut self,
+        joins: Vec<SqlJoin>,
+        order_by_components: &mut [SqlOrderByComponent],
+    ) -> Result<(), SqlJoinsInsertError>;
 }
 
 impl SqlJoinsOps for Vec<SqlJoin> {
@@ -246,6 +449,7 @@ impl SqlJoinsOps for Vec<SqlJoin> {
         {
             if existing_join.other_column_name != join.other_column_name
                 || existing_join.real_table_name != join.real_table_name
+                || existing_join.schema_name != join.schema_name
                 || existing_join.using_table_name != join.using_table_name
                 || existing_join.using_column_name != join.using_column_name
             {
@@ -259,6 +463,80 @@ impl SqlJoinsOps for Vec<SqlJoin> {
             Ok(true)
         }
     }
+
+    fn prefix_aliases(
+        &mut self,
+        prefix: &str,
+        base_table_name: Option<TableName>,
+        order_by_components: &mut [SqlOrderByComponent],
+    ) {
+        let aliases: HashMap<TableName, TableName> = self
+            .iter()
+            .map(|join| {
+                (
+                    join.other_table_name.clone(),
+                    Name::Dynamic(format!("{prefix}{}", join.other_table_name)),
+                )
+            })
+            .collect();
+
+        let rename = |table_name: &mut TableName| {
+            if let Some(alias) = aliases.get(table_name) {
+                *table_name = alias.clone();
+            } else if let Some(base_table_name) = &base_table_name {
+                *table_name = base_table_name.clone();
+            }
+        };
+
+        for join in self.iter_mut() {
+            if join.real_table_name.is_none() {
+                join.real_table_name = Some(join.other_table_name.clone());
+            }
+
+            rename(&mut join.other_table_name);
+            rename(&mut join.using_table_name);
+        }
+
+        for order_by_component in order_by_components {
+            rename(&mut order_by_component.table_name);
+        }
+    }
+
+    fn merge_joins(
+        &mut self,
+        joins: Vec<SqlJoin>,
+        order_by_components: &mut [SqlOrderByComponent],
+    ) -> Result<(), SqlJoinsInsertError> {
+        let mut merged = self.clone();
+        // the aliases of the reused joins
+        let mut aliases: HashMap<TableName, TableName> = HashMap::new();
+
+        for mut join in joins {
+            if let Some(alias) = aliases.get(&join.using_table_name) {
+                join.using_table_name = alias.clone();
+            }
+
+            if let Some(existing_join) =
+                merged.iter().find(|existing_join| existing_join.is_equivalent(&join))
+            {
+                if existing_join.other_table_name != join.other_table_name {
+                    aliases.insert(join.other_table_name, existing_join.other_table_name.clone());
+                }
+            } else {
+                merged.add_join(join)?;
+            }
+        }
+
+        for order_by_component in order_by_components {
+            if let Some(alias) = aliases.get(&order_by_component.table_name) {
+                order_by_component.table_name = alias.clone();
+            }
+        }
+
+        *self = merged;
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "mysql", feature = "sqlite", feature = "mssql", feature = "mssql2008"))]
+use crate::TableColumn;
 use crate::{ColumnName, OrderType, TableName};
 
 #[derive(Debug, Clone)]
@@ -7,6 +9,18 @@ pub enum NullStrategy {
     Last,
 }
 
+impl NullStrategy {
+    /// Returns the strategy which puts `NULL`s on the other side. `Default` stays `Default` because databases already flip the position of `NULL`s with the order type.
+    #[inline]
+    pub const fn reverse(&self) -> Self {
+        match self {
+            Self::Default => Self::Default,
+            Self::First => Self::Last,
+            Self::Last => Self::First,
+        }
+    }
+}
+
 /// Struct for generating the `ORDER BY` clause.
 #[derive(Debug, Clone)]
 pub struct SqlOrderByComponent {
@@ -16,6 +30,30 @@ pub struct SqlOrderByComponent {
     pub null_strategy: NullStrategy,
 }
 
+impl SqlOrderByComponent {
+    /// Flip the order type and the null strategy, so that the component sorts rows in exactly the opposite order.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.order_type = self.order_type.reverse();
+        self.null_strategy = self.null_strategy.reverse();
+    }
+}
+
+/// Operators for `SqlOrderByComponent`s.
+pub trait SqlOrderByComponentsOps {
+    /// Reverse every component, so that the `ORDER BY` clause sorts rows in exactly the opposite order. Rows fetched with the reversed order have to be reversed again in memory.
+    fn reverse_order(&mut self);
+}
+
+impl SqlOrderByComponentsOps for [SqlOrderByComponent] {
+    #[inline]
+    fn reverse_order(&mut self) {
+        for order_by_component in self.iter_mut() {
+            order_by_component.reverse();
+        }
+    }
+}
+
 #[cfg(any(feature = "mysql", feature = "sqlite"))]
 impl SqlOrderByComponent {
     fn to_sql_order_by_clause_component<'a>(&self, s: &'a mut String) -> &'a str {
@@ -81,6 +119,43 @@ impl SqlOrderByComponent {
 
         unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
     }
+
+    fn format_sql_row_number<'a>(
+        partition_by: &[TableColumn],
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: &str,
+        s: &'a mut String,
+    ) -> &'a str {
+        use std::{fmt::Write, str::from_utf8_unchecked};
+
+        let len = s.len();
+
+        s.push_str("ROW_NUMBER() OVER (");
+
+        if !partition_by.is_empty() {
+            s.push_str("PARTITION BY ");
+
+            for (table_name, column_name) in partition_by {
+                s.write_fmt(format_args!("`{table_name}`.`{column_name}`, ")).unwrap();
+            }
+
+            unsafe {
+                let len = s.len();
+
+                s.as_mut_vec().truncate(len - 2);
+            }
+
+            if !order_by_components.is_empty() {
+                s.push(' ');
+            }
+        }
+
+        Self::format_sql_order_by_components(order_by_components, s);
+
+        s.write_fmt(format_args!(") AS `{row_number_column_name}`")).unwrap();
+
+        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+    }
 }
 
 #[cfg(feature = "mysql")]
@@ -109,6 +184,44 @@ impl SqlOrderByComponent {
     ) -> &'a str {
         Self::format_sql_order_by_components(order_by_components, s)
     }
+
+    /// Generate a `ROW_NUMBER` column for MySQL.
+    ///
+    /// ```sql
+    /// ROW_NUMBER() OVER ([ORDER BY <SqlOrderByComponent[0]>, <SqlOrderByComponent[1]>]) AS `<row_number_column_name>`
+    /// ```
+    ///
+    /// Select it in a subquery and filter the outer query with `PaginationOptions::to_mysql_row_number_filter`. This also works for `DISTINCT` or grouped results, and the column carries the absolute position of each row.
+    #[inline]
+    pub fn format_mysql_row_number<'a>(
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_row_number(&[], order_by_components, row_number_column_name.as_ref(), s)
+    }
+
+    /// Generate a `ROW_NUMBER` column which numbers rows within each partition, for MySQL.
+    ///
+    /// ```sql
+    /// ROW_NUMBER() OVER (PARTITION BY `<table_name>`.`<column_name>`[, ...] [ORDER BY <SqlOrderByComponent[0]>, <SqlOrderByComponent[1]>]) AS `<row_number_column_name>`
+    /// ```
+    ///
+    /// The partition columns and the joins they need can be created by `OrderByOptions::to_partitioned_sql`. Filter the outer query with `PaginationOptions::to_mysql_row_number_filter` to paginate rows within every partition, e.g. the first page fetches the top N rows per group.
+    #[inline]
+    pub fn format_mysql_partitioned_row_number<'a>(
+        partition_by: &[TableColumn],
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_row_number(
+            partition_by,
+            order_by_components,
+            row_number_column_name.as_ref(),
+            s,
+        )
+    }
 }
 
 #[cfg(feature = "sqlite")]
@@ -137,6 +250,44 @@ impl SqlOrderByComponent {
     ) -> &'a str {
         Self::format_sql_order_by_components(order_by_components, s)
     }
+
+    /// Generate a `ROW_NUMBER` column for SQLite.
+    ///
+    /// ```sql
+    /// ROW_NUMBER() OVER ([ORDER BY <SqlOrderByComponent[0]>, <SqlOrderByComponent[1]>]) AS `<row_number_column_name>`
+    /// ```
+    ///
+    /// Select it in a subquery and filter the outer query with `PaginationOptions::to_sqlite_row_number_filter`. This also works for `DISTINCT` or grouped results, and the column carries the absolute position of each row.
+    #[inline]
+    pub fn format_sqlite_row_number<'a>(
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_row_number(&[], order_by_components, row_number_column_name.as_ref(), s)
+    }
+
+    /// Generate a `ROW_NUMBER` column which numbers rows within each partition, for SQLite.
+    ///
+    /// ```sql
+    /// ROW_NUMBER() OVER (PARTITION BY `<table_name>`.`<column_name>`[, ...] [ORDER BY <SqlOrderByComponent[0]>, <SqlOrderByComponent[1]>]) AS `<row_number_column_name>`
+    /// ```
+    ///
+    /// The partition columns and the joins they need can be created by `OrderByOptions::to_partitioned_sql`. Filter the outer query with `PaginationOptions::to_sqlite_row_number_filter` to paginate rows within every partition, e.g. the first page fetches the top N rows per group.
+    #[inline]
+    pub fn format_sqlite_partitioned_row_number<'a>(
+        partition_by: &[TableColumn],
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_row_number(
+            partition_by,
+            order_by_components,
+            row_number_column_name.as_ref(),
+            s,
+        )
+    }
 }
 
 #[cfg(any(feature = "mssql", feature = "mssql2008"))]
@@ -204,6 +355,46 @@ impl SqlOrderByComponent {
 
         unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
     }
+
+    fn format_sql_row_number_ms<'a>(
+        partition_by: &[TableColumn],
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: &str,
+        s: &'a mut String,
+    ) -> &'a str {
+        use std::{fmt::Write, str::from_utf8_unchecked};
+
+        let len = s.len();
+
+        s.push_str("ROW_NUMBER() OVER (");
+
+        if !partition_by.is_empty() {
+            s.push_str("PARTITION BY ");
+
+            for (table_name, column_name) in partition_by {
+                s.write_fmt(format_args!("[{table_name}].[{column_name}], ")).unwrap();
+            }
+
+            unsafe {
+                let len = s.len();
+
+                s.as_mut_vec().truncate(len - 2);
+            }
+
+            s.push(' ');
+        }
+
+        if order_by_components.is_empty() {
+            // the ORDER BY clause is required by ROW_NUMBER in SQL Server
+            s.push_str("ORDER BY (SELECT NULL)");
+        } else {
+            Self::format_sql_order_by_components_ms(order_by_components, s);
+        }
+
+        s.write_fmt(format_args!(") AS [{row_number_column_name}]")).unwrap();
+
+        unsafe { from_utf8_unchecked(&s.as_bytes()[len..]) }
+    }
 }
 
 #[cfg(any(feature = "mssql", feature = "mssql2008"))]
@@ -232,4 +423,44 @@ impl SqlOrderByComponent {
     ) -> &'a str {
         Self::format_sql_order_by_components_ms(order_by_components, s)
     }
+
+    /// Generate a `ROW_NUMBER` column for Microsoft SQL Server.
+    ///
+    /// ```sql
+    /// ROW_NUMBER() OVER ([ORDER BY <SqlOrderByComponent[0]>, <SqlOrderByComponent[1]>]) AS [<row_number_column_name>]
+    /// ```
+    ///
+    /// If there is no component, `ORDER BY (SELECT NULL)` is used because SQL Server requires an `ORDER BY` clause for `ROW_NUMBER`.
+    ///
+    /// Select it in a subquery and filter the outer query with `PaginationOptions::to_mssql_row_number_filter`. This also works for `DISTINCT` or grouped results, and the column carries the absolute position of each row.
+    #[inline]
+    pub fn format_mssql_row_number<'a>(
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_row_number_ms(&[], order_by_components, row_number_column_name.as_ref(), s)
+    }
+
+    /// Generate a `ROW_NUMBER` column which numbers rows within each partition, for Microsoft SQL Server.
+    ///
+    /// ```sql
+    /// ROW_NUMBER() OVER (PARTITION BY [<table_name>].[<column_name>][, ...] [ORDER BY <SqlOrderByComponent[0]>, <SqlOrderByComponent[1]>]) AS [<row_number_column_name>]
+    /// ```
+    ///
+    /// The partition columns and the joins they need can be created by `OrderByOptions::to_partitioned_sql`. Filter the outer query with `PaginationOptions::to_mssql_row_number_filter` to paginate rows within every partition, e.g. the first page fetches the top N rows per group.
+    #[inline]
+    pub fn format_mssql_partitioned_row_number<'a>(
+        partition_by: &[TableColumn],
+        order_by_components: &[SqlOrderByComponent],
+        row_number_column_name: impl AsRef<str>,
+        s: &'a mut String,
+    ) -> &'a str {
+        Self::format_sql_row_number_ms(
+            partition_by,
+            order_by_components,
+            row_number_column_name.as_ref(),
+            s,
+        )
+    }
 }
@@ -1,5 +1,47 @@
+use std::{num::ParseIntError, str::FromStr};
+
 use rdb_pagination_core::*;
 
+/// An `OrderMethodValue` which is not built in.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Priority(i16);
+
+impl FromStr for Priority {
+    type Err = ParseIntError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl OrderMethodValue for Priority {
+    #[inline]
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Self(1)
+    }
+
+    #[inline]
+    fn abs(&self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    #[inline]
+    fn from_isize(value: isize) -> Option<Self> {
+        i16::try_from(value).ok().map(Self)
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        self.0 as isize
+    }
+}
+
 #[test]
 fn limit_offset() {
     let mut buffer = String::new();
@@ -434,31 +476,22 @@ fn order_by() {
 
         let (mut joins, _) = order_builder.build();
 
-        let result = joins.add_join(SqlJoin {
-            other_table_name:  Name::Static("component_type"),
-            other_column_name: Name::Static("id"),
-            real_table_name:   None,
-            using_table_name:  Name::Static("component"),
-            using_column_name: Name::Static("component_type_id"),
-        });
+        let result = joins.add_join(SqlJoin::new(
+            (Name::Static("component_type"), Name::Static("id")),
+            (Name::Static("component"), Name::Static("component_type_id")),
+        ));
         assert!(matches!(result, Ok(false)));
 
-        let result = joins.add_join(SqlJoin {
-            other_table_name:  Name::Static("component_type2"),
-            other_column_name: Name::Static("id"),
-            real_table_name:   None,
-            using_table_name:  Name::Static("component"),
-            using_column_name: Name::Static("component_type_id"),
-        });
+        let result = joins.add_join(SqlJoin::new(
+            (Name::Static("component_type2"), Name::Static("id")),
+            (Name::Static("component"), Name::Static("component_type_id")),
+        ));
         assert!(matches!(result, Ok(true)));
 
-        let result = joins.add_join(SqlJoin {
-            other_table_name:  Name::Static("component_type"),
-            other_column_name: Name::Static("id"),
-            real_table_name:   None,
-            using_table_name:  Name::Static("component"),
-            using_column_name: Name::Static("id"),
-        });
+        let result = joins.add_join(SqlJoin::new(
+            (Name::Static("component_type"), Name::Static("id")),
+            (Name::Static("component"), Name::Static("id")),
+        ));
         assert!(result.is_err());
     }
 }
@@ -534,3 +567,1210 @@ fn null_strategy() {
         SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
     );
 }
+
+#[test]
+fn explain() {
+    let mut relationship = Relationship::new(Name::Static("component"));
+
+    relationship
+        .join_check(
+            (Name::Static("component"), Name::Static("component_type_id")),
+            (Name::Static("component_type"), Name::Static("id")),
+            None,
+        )
+        .unwrap();
+
+    let order_options = [
+        ("type_id", (Name::Static("component_type"), Name::Static("id")), true, 2i8),
+        ("type_order", (Name::Static("component_type"), Name::Static("order")), false, 3),
+        ("type_name", (Name::Static("component_type"), Name::Static("name")), true, 0),
+        ("id", (Name::Static("component"), Name::Static("id")), true, -4),
+        ("code", (Name::Static("component_type"), Name::Static("code")), false, 1),
+    ];
+
+    let mut order_builder = OrderBuilder::new(relationship, order_options.len());
+
+    for (field_name, table_column, unique, order_method) in order_options {
+        order_builder.add_named_order_option(
+            Name::Static(field_name),
+            table_column,
+            unique,
+            NullStrategy::Default,
+            order_method.into(),
+        );
+    }
+
+    let explanation = order_builder.explain();
+
+    assert!(matches!(explanation.options[0].resolution, OrderOptionResolution::Applied {
+        order_type: OrderType::Asc,
+    }));
+    assert!(matches!(
+        &explanation.options[1].resolution,
+        OrderOptionResolution::Rewritten { table_column, .. }
+            if table_column.1.as_ref() == "component_type_id"
+    ));
+    assert!(matches!(
+        &explanation.options[2].resolution,
+        OrderOptionResolution::DroppedByUnique { unique_table_column }
+            if unique_table_column.1.as_ref() == "id"
+    ));
+    assert!(matches!(explanation.options[4].resolution, OrderOptionResolution::Disabled));
+
+    assert_eq!(
+        "options:\n    code (component_type.code): applied, ASC\n    type_id (component_type.id): \
+         rewritten to component.component_type_id ASC\n    type_order (component_type.order): \
+         dropped, component_type.id is unique and has a higher priority\n    id (component.id): \
+         applied, DESC\n    type_name (component_type.name): disabled\njoins:\n    component_type \
+         on component_type.id = component.component_type_id, needed by component_type.code",
+        explanation.to_string()
+    );
+}
+
+#[test]
+fn page_query() {
+    let options = PaginationOptions::new().page(2).items_per_page(20);
+
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: Some(20), offset: 20
+        },
+        options.page_query(990)
+    );
+
+    let options = options.page(50);
+
+    assert_eq!(
+        PageQuery {
+            reversed: true, limit: Some(10), offset: 0
+        },
+        options.page_query(990)
+    );
+
+    let options = options.page(100);
+
+    assert_eq!(
+        PageQuery {
+            reversed: true, limit: Some(10), offset: 0
+        },
+        options.page_query(990)
+    );
+
+    let options = options.page(48);
+
+    assert_eq!(
+        PageQuery {
+            reversed: true, limit: Some(20), offset: 30
+        },
+        options.page_query(990)
+    );
+
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: Some(20), offset: 0
+        },
+        options.page_query(0)
+    );
+
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: None, offset: 0
+        },
+        options.items_per_page(0).page_query(990)
+    );
+}
+
+#[test]
+fn at_offset() {
+    let mut buffer = String::new();
+
+    let options = PaginationOptions::new().items_per_page(20).at_offset(35);
+
+    assert_eq!(35, options.offset);
+    assert_eq!(Some(20), options.limit);
+
+    #[cfg(feature = "mysql")]
+    assert_eq!("LIMIT 20 OFFSET 35", options.to_mysql_limit_offset(&mut buffer));
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!("LIMIT 20 OFFSET 35", options.to_sqlite_limit_offset(&mut buffer));
+
+    #[cfg(feature = "mssql")]
+    assert_eq!(
+        "OFFSET 35 ROWS FETCH NEXT 20 ROWS ONLY",
+        options.to_mssql_limit_offset(&mut buffer)
+    );
+
+    #[cfg(feature = "mssql2008")]
+    assert_eq!(
+        "WHERE [rn] BETWEEN 36 AND 55",
+        options.to_mssql2008_limit_offset("rn", &mut buffer)
+    );
+
+    buffer.clear();
+
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: Some(20), offset: 35
+        },
+        options.page_query(990)
+    );
+
+    assert_eq!(
+        PageQuery {
+            reversed: true, limit: Some(20), offset: 15
+        },
+        options.clone().offset(955).page_query(990)
+    );
+
+    assert_eq!(
+        PageQuery {
+            reversed: true, limit: Some(5), offset: 0
+        },
+        options.clone().offset(985).page_query(990)
+    );
+
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: Some(20), offset: 1000
+        },
+        options.clone().offset(1000).page_query(990)
+    );
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).at_offset(35);
+
+    assert_eq!(2, pagination.get_page());
+    assert_eq!(35, pagination.get_offset());
+    assert!(pagination.is_offset_based());
+
+    let pagination = Pagination::new().at_offset(35).total_items(50).items_per_page(20);
+
+    assert_eq!(2, pagination.get_page());
+
+    let pagination = pagination.at_offset(100);
+
+    assert_eq!(3, pagination.get_page());
+    assert_eq!(100, pagination.get_offset());
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).page(3);
+
+    assert_eq!(40, pagination.get_offset());
+    assert!(!pagination.is_offset_based());
+
+    let pagination = pagination.at_offset(15).page(2);
+
+    assert_eq!(20, pagination.get_offset());
+    assert!(!pagination.is_offset_based());
+}
+
+#[test]
+fn try_offset() {
+    let options = PaginationOptions::new().page(3).items_per_page(20);
+
+    assert_eq!(Ok(40), options.try_offset());
+    assert_eq!(Ok(40), options.try_offset_with_max_page(3));
+    assert_eq!(
+        Err(OffsetError::PageTooLarge {
+            page: 3, max_page: 2
+        }),
+        options.try_offset_with_max_page(2)
+    );
+
+    let options = PaginationOptions::new().page(usize::MAX).items_per_page(usize::MAX);
+
+    assert_eq!(Err(OffsetError::Overflow), options.try_offset());
+    assert_eq!(u64::MAX, options.offset());
+
+    let options = PaginationOptions::new().page(usize::MAX).items_per_page(0);
+
+    assert_eq!(Ok(0), options.try_offset());
+
+    let options = OffsetPaginationOptions::new().offset(1000).limit(20);
+
+    assert_eq!(Ok(1000), options.try_offset_with_max_page(51));
+    assert_eq!(
+        Err(OffsetError::PageTooLarge {
+            page: 51, max_page: 50
+        }),
+        options.try_offset_with_max_page(50)
+    );
+}
+
+#[test]
+fn pager() {
+    use PageItem::{Ellipsis, Page};
+
+    let pagination = Pagination::new().items_per_page(10).total_items(200);
+
+    assert_eq!(
+        vec![Page(1), Page(2), Ellipsis, Page(20)],
+        pagination.clone().page(1).page_window(1, 1)
+    );
+    assert_eq!(
+        vec![Page(1), Page(2), Page(3), Page(4), Page(5), Ellipsis, Page(20)],
+        pagination.clone().page(4).page_window(1, 1)
+    );
+    assert_eq!(
+        vec![Page(1), Ellipsis, Page(9), Page(10), Page(11), Ellipsis, Page(20)],
+        pagination.clone().page(10).page_window(1, 1)
+    );
+    assert_eq!(vec![Ellipsis, Page(10), Ellipsis], pagination.clone().page(10).page_window(0, 0));
+    assert_eq!(
+        (1..=20).map(Page).collect::<Vec<_>>(),
+        pagination.clone().page(10).page_window(10, 2)
+    );
+    assert!(Pagination::new().items_per_page(10).page_window(1, 1).is_empty());
+
+    // the window is built without visiting every page
+    assert_eq!(
+        vec![
+            Page(1),
+            Ellipsis,
+            Page(149_999_999),
+            Page(150_000_000),
+            Page(150_000_001),
+            Ellipsis,
+            Page(300_000_000)
+        ],
+        Pagination::new()
+            .items_per_page(1)
+            .total_items(300_000_000)
+            .page(150_000_000)
+            .page_window(1, 1)
+    );
+    assert_eq!(
+        vec![Ellipsis, Page(usize::MAX - 1), Page(usize::MAX)],
+        Pagination::new()
+            .items_per_page(1)
+            .total_items(usize::MAX)
+            .page(usize::MAX)
+            .page_window(1, 0)
+    );
+
+    let pagination = pagination.page(11);
+
+    assert_eq!(Some(12), pagination.next_page());
+    assert_eq!(Some(10), pagination.prev_page());
+    assert_eq!(100..110, pagination.offset_range());
+    assert_eq!(Some(101..=110), pagination.item_range());
+
+    let pagination = Pagination::new().items_per_page(20).total_items(1234).page(62);
+
+    assert_eq!(None, pagination.next_page());
+    assert_eq!(Some(1221..=1234), pagination.item_range());
+
+    let pagination = Pagination::new().items_per_page(20).total_items(1234).page(1);
+
+    assert_eq!(None, pagination.prev_page());
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).at_offset(35);
+
+    assert_eq!(35..50, pagination.offset_range());
+    assert_eq!(Some(36..=50), pagination.item_range());
+
+    let pagination = Pagination::new().items_per_page(20);
+
+    assert_eq!(0..0, pagination.offset_range());
+    assert_eq!(None, pagination.item_range());
+    assert_eq!(None, pagination.next_page());
+
+    let pagination = Pagination::new().total_items(50);
+
+    assert_eq!(Some(1..=50), pagination.item_range());
+}
+
+#[test]
+fn to_pagination() {
+    let options = PaginationOptions::new().page(2).items_per_page(20);
+
+    let pagination = options.to_pagination(50);
+
+    assert_eq!(2, pagination.get_page());
+    assert_eq!(3, pagination.get_total_pages());
+    assert_eq!(50, pagination.get_total_items());
+    assert!(!options.is_out_of_range(50));
+
+    let AnyPaginationOptions::Page(options) = pagination.to_options(()) else {
+        panic!("expected page-based options");
+    };
+
+    assert_eq!(2, options.page);
+    assert_eq!(20, options.items_per_page);
+
+    let options = options.page(5);
+
+    assert_eq!(3, options.to_pagination(50).get_page());
+    assert!(options.is_out_of_range(50));
+    assert!(options.is_out_of_range(0));
+
+    let options = options.page(0);
+
+    assert_eq!(1, options.to_pagination(50).get_page());
+    assert!(!options.is_out_of_range(50));
+    assert!(!options.page(1).is_out_of_range(0));
+
+    let options = PaginationOptions::new().items_per_page(20).at_offset(35);
+
+    let pagination = options.to_pagination(50);
+
+    assert_eq!(2, pagination.get_page());
+    assert_eq!(35, pagination.get_offset());
+    assert!(!options.is_out_of_range(50));
+    assert!(options.is_out_of_range(35));
+    assert!(!options.clone().offset(0).is_out_of_range(0));
+
+    let AnyPaginationOptions::Offset(options) = pagination.to_options(()) else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(35, options.offset);
+    assert_eq!(Some(20), options.limit);
+}
+
+#[test]
+fn out_of_range_policy() {
+    let pagination = Pagination::new().items_per_page(20).total_items(50).page(5);
+
+    assert_eq!(3, pagination.get_page());
+    assert!(!pagination.is_out_of_range());
+    assert!(pagination.validate().is_ok());
+
+    let pagination = Pagination::new()
+        .out_of_range_policy(OutOfRangePolicy::Empty)
+        .items_per_page(20)
+        .total_items(50)
+        .page(5);
+
+    assert_eq!(5, pagination.get_page());
+    assert!(pagination.is_out_of_range());
+    assert!(pagination.validate().is_ok());
+    assert_eq!(50..50, pagination.offset_range());
+    assert_eq!(None, pagination.item_range());
+
+    let pagination = pagination.out_of_range_policy(OutOfRangePolicy::Error);
+
+    assert_eq!(
+        Err(PageOutOfRangeError {
+            page: 5, total_pages: 3
+        }),
+        pagination.validate()
+    );
+
+    let pagination = pagination.out_of_range_policy(OutOfRangePolicy::Clamp);
+
+    assert_eq!(3, pagination.get_page());
+    assert!(pagination.validate().is_ok());
+
+    #[derive(Default)]
+    struct ErrorOrderBy;
+
+    impl OrderByOptions for ErrorOrderBy {
+        const OUT_OF_RANGE_POLICY: OutOfRangePolicy = OutOfRangePolicy::Error;
+    }
+
+    let options = PaginationOptions::<ErrorOrderBy>::default().page(5).items_per_page(20);
+
+    assert_eq!(5, options.to_pagination(50).get_page());
+    assert!(options.try_to_pagination(50).is_err());
+    assert!(options.try_to_pagination(100).is_ok());
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: Some(20), offset: 80
+        },
+        options.page_query(50)
+    );
+}
+
+#[test]
+fn order_method_values() {
+    let relationship = Relationship::new(Name::Static("user"));
+
+    let mut order_builder: OrderBuilder<i32> = OrderBuilder::new(relationship.clone(), 3);
+
+    order_builder.add_order_option(
+        (Name::Static("user"), Name::Static("name")),
+        false,
+        NullStrategy::Default,
+        OrderMethod(-2000),
+    );
+    order_builder.add_order_option(
+        (Name::Static("user"), Name::Static("email")),
+        false,
+        NullStrategy::Default,
+        OrderMethod(0),
+    );
+    order_builder.add_order_option(
+        (Name::Static("user"), Name::Static("id")),
+        true,
+        NullStrategy::Default,
+        OrderMethod(3000),
+    );
+
+    let (_, order_by_components) = order_builder.build();
+
+    assert_eq!(2, order_by_components.len());
+    assert_eq!(OrderType::Desc, order_by_components[0].order_type);
+    assert_eq!("id", order_by_components[1].column_name.as_ref());
+
+    assert_eq!(Some(OrderMethod(-3i64)), OrderMethod::from_priority(3, OrderType::Desc));
+
+    let disabled = OrderMethod(OptionNonZeroI16(None));
+
+    assert_eq!(OrderMethod::default(), disabled);
+    assert_eq!(None, disabled.order_type());
+    assert_eq!(
+        Some(OrderMethod(OptionNonZeroI16::from(-2))),
+        OrderMethod::from_priority(2, OrderType::Desc)
+    );
+    assert_eq!("0".parse(), Ok(OptionNonZeroI16(None)));
+
+    let mut order_builder: OrderBuilder<OptionNonZeroI16> = OrderBuilder::new(relationship, 2);
+
+    order_builder.add_order_option(
+        (Name::Static("user"), Name::Static("name")),
+        false,
+        NullStrategy::Default,
+        OrderMethod(OptionNonZeroI16::from(-2)),
+    );
+    order_builder.add_order_option(
+        (Name::Static("user"), Name::Static("id")),
+        true,
+        NullStrategy::Default,
+        disabled,
+    );
+
+    let (_, order_by_components) = order_builder.build();
+
+    assert_eq!(1, order_by_components.len());
+    assert_eq!(OrderType::Desc, order_by_components[0].order_type);
+}
+
+#[test]
+fn custom_order_method_value() {
+    assert_eq!(Some(Priority(-3)), Priority::from_isize(-3));
+    assert_eq!(None, Priority::from_isize(isize::MAX));
+
+    assert_eq!(Some(OrderMethod(Priority(2))), OrderMethod::from_priority(2, OrderType::Asc));
+    assert_eq!(None, OrderMethod::<Priority>::from_priority(40000, OrderType::Desc));
+
+    assert_eq!(0, Priority(0).to_isize());
+    assert_eq!(1, Priority(1).to_isize());
+    assert_eq!(-300, Priority(-300).to_isize());
+    assert_eq!(i16::MAX as isize, Priority(i16::MAX).to_isize());
+    assert_eq!(i16::MIN as isize, Priority(i16::MIN).to_isize());
+
+    let limits = OrderMethodLimits {
+        max_priority: Some(2), max_fields: None
+    };
+
+    assert!(limits.validate(&[("id", OrderMethod(Priority(-2)))]).is_ok());
+    assert!(limits.validate(&[("id", OrderMethod(Priority(3)))]).is_err());
+}
+
+#[test]
+fn reverse_order() {
+    let mut order_by_components = [
+        SqlOrderByComponent {
+            table_name:    Name::Static("component"),
+            column_name:   Name::Static("order"),
+            order_type:    OrderType::Asc,
+            null_strategy: NullStrategy::First,
+        },
+        SqlOrderByComponent {
+            table_name:    Name::Static("component"),
+            column_name:   Name::Static("id"),
+            order_type:    OrderType::Desc,
+            null_strategy: NullStrategy::Default,
+        },
+    ];
+
+    order_by_components.reverse_order();
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `component`.`order` IS NULL, `component`.`order` DESC, `component`.`id` ASC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `component`.`order` IS NULL, `component`.`order` DESC, `component`.`id` ASC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY CASE WHEN [component].[order] IS NULL THEN 1 ELSE 0 END, [component].[order] \
+         DESC, [component].[id] ASC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    assert!(matches!(order_by_components[1].order_type, OrderType::Asc));
+}
+
+#[test]
+fn row_number() {
+    let relationship = Relationship::new(Name::Static("component"));
+
+    let mut order_builder = OrderBuilder::new(relationship, 1);
+
+    order_builder.add_order_option(
+        (Name::Static("component"), Name::Static("id")),
+        true,
+        NullStrategy::Default,
+        OrderMethod(-1i8),
+    );
+
+    #[allow(unused_variables)]
+    let (_joins, order_by_components) = order_builder.build();
+
+    #[allow(unused_variables)]
+    let pagination_options = PaginationOptions::new().page(3).items_per_page(20);
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "ROW_NUMBER() OVER (ORDER BY `component`.`id` DESC) AS `rn`",
+            SqlOrderByComponent::format_mysql_row_number(&order_by_components, "rn", &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ROW_NUMBER() OVER () AS `rn`",
+            SqlOrderByComponent::format_mysql_row_number(&[], "rn", &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "WHERE `rn` BETWEEN 41 AND 60",
+            pagination_options.to_mysql_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "ROW_NUMBER() OVER (ORDER BY `component`.`id` DESC) AS `rn`",
+            SqlOrderByComponent::format_sqlite_row_number(&order_by_components, "rn", &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "WHERE `rn` > 40",
+            PageQuery {
+                reversed: false, limit: None, offset: 40
+            }
+            .to_sqlite_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "ROW_NUMBER() OVER (ORDER BY [component].[id] DESC) AS [rn]",
+            SqlOrderByComponent::format_mssql_row_number(&order_by_components, "rn", &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ROW_NUMBER() OVER (ORDER BY (SELECT NULL)) AS [rn]",
+            SqlOrderByComponent::format_mssql_row_number(&[], "rn", &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "WHERE [rn] <= 20",
+            pagination_options.clone().page(1).to_mssql_row_number_filter("rn", &mut buffer)
+        );
+    }
+}
+
+#[test]
+fn row_number_filter_max_offset() {
+    #[allow(unused_variables)]
+    let page_query = PageQuery {
+        reversed: false, limit: Some(20), offset: u64::MAX
+    };
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "WHERE `rn` BETWEEN 9223372036854775807 AND 9223372036854775807",
+            page_query.to_mysql_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "WHERE `rn` BETWEEN 9223372036854775807 AND 9223372036854775807",
+            page_query.to_sqlite_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "WHERE [rn] BETWEEN 9223372036854775807 AND 9223372036854775807",
+            page_query.to_mssql_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[allow(unused_variables)]
+    let page_query = PageQuery {
+        reversed: false, limit: None, offset: u64::MAX
+    };
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "WHERE `rn` > 9223372036854775807",
+            page_query.to_mysql_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "WHERE `rn` > 9223372036854775807",
+            page_query.to_sqlite_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "mssql2008")]
+    {
+        assert_eq!(
+            "WHERE [rn] > 9223372036854775807",
+            page_query.to_mssql2008_limit_offset("rn", &mut buffer)
+        );
+    }
+}
+
+#[test]
+fn limit_offset_max_offset() {
+    // the offset saturates at `u64::MAX`
+    let options = PaginationOptions::new().page(usize::MAX).items_per_page(usize::MAX);
+
+    assert_eq!(u64::MAX, options.offset());
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "LIMIT 9223372036854775807 OFFSET 9223372036854775807",
+            options.to_mysql_limit_offset(&mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "LIMIT 9223372036854775807 OFFSET 9223372036854775807",
+            options.to_sqlite_limit_offset(&mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "mssql")]
+    {
+        assert_eq!(
+            "OFFSET 9223372036854775807 ROWS FETCH NEXT 9223372036854775807 ROWS ONLY",
+            options.to_mssql_limit_offset(&mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "mssql2008")]
+    {
+        assert_eq!(
+            "WHERE [rn] BETWEEN 9223372036854775807 AND 9223372036854775807",
+            options.to_mssql2008_limit_offset("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[allow(unused_variables)]
+    let options = OffsetPaginationOptions::new().offset(u64::MAX);
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!("OFFSET 9223372036854775807", options.to_mysql_limit_offset(&mut buffer));
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!("OFFSET 9223372036854775807", options.to_sqlite_limit_offset(&mut buffer));
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "mssql")]
+    {
+        assert_eq!("OFFSET 9223372036854775807 ROWS", options.to_mssql_limit_offset(&mut buffer));
+    }
+}
+
+#[test]
+fn limit_offset_empty_page() {
+    #[allow(unused_variables)]
+    let options = OffsetPaginationOptions::new().offset(40).limit(0);
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!("LIMIT 0 OFFSET 40", options.to_mysql_limit_offset(&mut buffer));
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!("LIMIT 0 OFFSET 40", options.to_sqlite_limit_offset(&mut buffer));
+
+        buffer.clear();
+    }
+
+    // SQL Server rejects `FETCH NEXT 0 ROWS`
+    #[cfg(feature = "mssql")]
+    {
+        assert_eq!("OFFSET 9223372036854775807 ROWS", options.to_mssql_limit_offset(&mut buffer));
+
+        buffer.clear();
+
+        assert_eq!(
+            "OFFSET 9223372036854775807 ROWS",
+            OffsetPaginationOptions::new().limit(0).to_mssql_limit_offset(&mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "mssql2008")]
+    {
+        assert_eq!(
+            "WHERE [rn] BETWEEN 41 AND 40",
+            options.to_mssql2008_limit_offset("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+}
+
+#[test]
+fn partitioned_row_number() {
+    let mut relationship = Relationship::new(Name::Static("comment"));
+
+    relationship
+        .join_check(
+            (Name::Static("comment"), Name::Static("post_id")),
+            (Name::Static("post"), Name::Static("id")),
+            None,
+        )
+        .unwrap();
+
+    relationship
+        .join_check(
+            (Name::Static("comment"), Name::Static("user_id")),
+            (Name::Static("user"), Name::Static("id")),
+            None,
+        )
+        .unwrap();
+
+    let mut order_builder = OrderBuilder::new(relationship, 2);
+
+    order_builder.add_order_option(
+        (Name::Static("comment"), Name::Static("created_at")),
+        false,
+        NullStrategy::Default,
+        OrderMethod(-1i8),
+    );
+
+    order_builder.add_order_option(
+        (Name::Static("comment"), Name::Static("id")),
+        true,
+        NullStrategy::Default,
+        OrderMethod(-2i8),
+    );
+
+    order_builder.add_partition_column((Name::Static("post"), Name::Static("id")));
+    order_builder.add_partition_column((Name::Static("user"), Name::Static("group_id")));
+
+    #[allow(unused_variables)]
+    let (joins, partition_by, order_by_components) = order_builder.build_partitioned();
+
+    assert_eq!(1, joins.len());
+    assert_eq!("user", joins[0].other_table_name.as_ref());
+    assert_eq!("post_id", partition_by[0].1.as_ref());
+
+    #[allow(unused_variables)]
+    let pagination_options = PaginationOptions::new().page(1).items_per_page(5);
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "ROW_NUMBER() OVER (PARTITION BY `comment`.`post_id`, `user`.`group_id` ORDER BY \
+             `comment`.`created_at` DESC, `comment`.`id` DESC) AS `rn`",
+            SqlOrderByComponent::format_mysql_partitioned_row_number(
+                &partition_by,
+                &order_by_components,
+                "rn",
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "WHERE `rn` <= 5",
+            pagination_options.to_mysql_row_number_filter("rn", &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "ROW_NUMBER() OVER (PARTITION BY `comment`.`post_id`, `user`.`group_id`) AS `rn`",
+            SqlOrderByComponent::format_sqlite_partitioned_row_number(
+                &partition_by,
+                &[],
+                "rn",
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "ROW_NUMBER() OVER (PARTITION BY [comment].[post_id], [user].[group_id] ORDER BY \
+             [comment].[created_at] DESC, [comment].[id] DESC) AS [rn]",
+            SqlOrderByComponent::format_mssql_partitioned_row_number(
+                &partition_by,
+                &order_by_components,
+                "rn",
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ROW_NUMBER() OVER (PARTITION BY [comment].[post_id] ORDER BY (SELECT NULL)) AS [rn]",
+            SqlOrderByComponent::format_mssql_partitioned_row_number(
+                &partition_by[..1],
+                &[],
+                "rn",
+                &mut buffer
+            )
+        );
+    }
+}
+
+#[test]
+fn schema_name() {
+    #[allow(unused_variables)]
+    let mut joins = [
+        SqlJoin::new(
+            (Name::Static("component_type"), Name::Static("id")),
+            (Name::Static("component"), Name::Static("component_type_id")),
+        ),
+        SqlJoin::new(
+            (Name::Static("vendor"), Name::Static("id")),
+            (Name::Static("component_type"), Name::Static("vendor_id")),
+        )
+        .real_table_name(Name::Static("company"))
+        .schema_name(Name::Static("shared")),
+    ];
+
+    #[allow(unused_variables)]
+    let qualify = |join: &SqlJoin| -> Option<TableName> {
+        assert_eq!("component_type", join.other_table_name.as_ref());
+
+        Some(Name::Dynamic(format!("tenant_{}", 42)))
+    };
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "LEFT JOIN `tenant_42`.`component_type` AS `component_type` ON `component_type`.`id` \
+             = `component`.`component_type_id`\nLEFT JOIN `shared`.`company` AS `vendor` ON \
+             `vendor`.`id` = `component_type`.`vendor_id`",
+            SqlJoin::format_mysql_join_clauses_with(&joins, qualify, &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "LEFT JOIN `component_type` ON `component_type`.`id` = \
+             `component`.`component_type_id`\nLEFT JOIN `shared`.`company` AS `vendor` ON \
+             `vendor`.`id` = `component_type`.`vendor_id`",
+            SqlJoin::format_sqlite_join_clauses(&joins, &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    joins[1].schema_name = Some(Name::Static("catalog.shared"));
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "LEFT JOIN [tenant_42].[component_type] AS [component_type] ON [component_type].[id] \
+             = [component].[component_type_id]\nLEFT JOIN [catalog].[shared].[company] AS \
+             [vendor] ON [vendor].[id] = [component_type].[vendor_id]",
+            SqlJoin::format_mssql_join_clauses_with(&joins, qualify, &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    // the quote characters in the schema names from `qualify` are escaped
+    #[allow(unused_variables)]
+    let qualify = |_: &SqlJoin| -> Option<TableName> { Some(Name::Static("a`b]c")) };
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "LEFT JOIN `a``b]c`.`component_type` AS `component_type` ON `component_type`.`id` = \
+             `component`.`component_type_id`",
+            SqlJoin::format_mysql_join_clauses_with(&joins[..1], qualify, &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "LEFT JOIN `a``b]c`.`component_type` AS `component_type` ON `component_type`.`id` = \
+             `component`.`component_type_id`",
+            SqlJoin::format_sqlite_join_clauses_with(&joins[..1], qualify, &mut buffer)
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "LEFT JOIN [a`b]]c].[component_type] AS [component_type] ON [component_type].[id] = \
+             [component].[component_type_id]",
+            SqlJoin::format_mssql_join_clauses_with(&joins[..1], qualify, &mut buffer)
+        );
+
+        buffer.clear();
+    }
+}
+
+#[test]
+fn prefix_aliases() {
+    let mut relationship = Relationship::new(Name::Static("component"));
+
+    relationship.join(
+        (Name::Static("component"), Name::Static("component_type_id")),
+        (Name::Static("component_type"), Name::Static("id")),
+        None,
+    );
+    relationship.join(
+        (Name::Static("component_type"), Name::Static("component_vendor_id")),
+        (Name::Static("component_vendor"), Name::Static("id")),
+        Some(Name::Static("vendor")),
+    );
+
+    let mut order_builder = OrderBuilder::new(relationship, 3);
+
+    order_builder.add_order_option(
+        (Name::Static("component_vendor"), Name::Static("order")),
+        false,
+        NullStrategy::Default,
+        OrderMethod::from(1i8),
+    );
+    order_builder.add_order_option(
+        (Name::Static("component"), Name::Static("id")),
+        true,
+        NullStrategy::Default,
+        OrderMethod::from(2i8),
+    );
+
+    let (mut joins, mut order_by_components) = order_builder.build();
+
+    joins.prefix_aliases("__ob_", Some(Name::Static("c")), &mut order_by_components);
+
+    assert_eq!(
+        vec![
+            SqlJoin::new(
+                (Name::Static("__ob_component_type"), Name::Static("id")),
+                (Name::Static("c"), Name::Static("component_type_id"))
+            )
+            .real_table_name(Name::Static("component_type")),
+            SqlJoin::new(
+                (Name::Static("__ob_component_vendor"), Name::Static("id")),
+                (Name::Static("__ob_component_type"), Name::Static("component_vendor_id"))
+            )
+            .real_table_name(Name::Static("vendor")),
+        ],
+        joins
+    );
+
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "LEFT JOIN `component_type` AS `__ob_component_type` ON `__ob_component_type`.`id` = \
+             `c`.`component_type_id`\nLEFT JOIN `vendor` AS `__ob_component_vendor` ON \
+             `__ob_component_vendor`.`id` = `__ob_component_type`.`component_vendor_id`",
+            SqlJoin::format_mysql_join_clauses(&joins, &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ORDER BY `__ob_component_vendor`.`order` ASC, `c`.`id` ASC",
+            SqlOrderByComponent::format_mysql_order_by_components(
+                &order_by_components,
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "ORDER BY [__ob_component_vendor].[order] ASC, [c].[id] ASC",
+            SqlOrderByComponent::format_mssql_order_by_components(
+                &order_by_components,
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+    }
+}
+
+#[test]
+fn merge_joins() {
+    let mut relationship = Relationship::new(Name::Static("component"));
+
+    relationship.join(
+        (Name::Static("component"), Name::Static("component_type_id")),
+        (Name::Static("component_type"), Name::Static("id")),
+        None,
+    );
+    relationship.join(
+        (Name::Static("component_type"), Name::Static("component_vendor_id")),
+        (Name::Static("component_vendor"), Name::Static("id")),
+        None,
+    );
+
+    let mut order_builder = OrderBuilder::new(relationship, 2);
+
+    order_builder.add_order_option(
+        (Name::Static("component_type"), Name::Static("order")),
+        false,
+        NullStrategy::Default,
+        OrderMethod::from(1i8),
+    );
+    order_builder.add_order_option(
+        (Name::Static("component_vendor"), Name::Static("order")),
+        false,
+        NullStrategy::Default,
+        OrderMethod::from(2i8),
+    );
+
+    let (joins, order_by_components) = order_builder.build();
+
+    // the larger query already joins `component_type` as `ct`
+    let mut query_joins = vec![
+        SqlJoin::new(
+            (Name::Static("ct"), Name::Static("id")),
+            (Name::Static("component"), Name::Static("component_type_id")),
+        )
+        .real_table_name(Name::Static("component_type")),
+    ];
+
+    let mut merged_order_by_components = order_by_components.clone();
+
+    query_joins.merge_joins(joins.clone(), &mut merged_order_by_components).unwrap();
+
+    assert_eq!(2, query_joins.len());
+    assert_eq!("ct", query_joins[1].using_table_name.as_ref());
+    assert_eq!("component_vendor", query_joins[1].other_table_name.as_ref());
+    assert_eq!("ct", merged_order_by_components[0].table_name.as_ref());
+    assert_eq!("component_vendor", merged_order_by_components[1].table_name.as_ref());
+
+    // the larger query uses `component_type` for another table
+    let mut query_joins = vec![SqlJoin::new(
+        (Name::Static("component_type"), Name::Static("id")),
+        (Name::Static("product"), Name::Static("component_type_id")),
+    )];
+
+    let mut merged_order_by_components = order_by_components.clone();
+
+    assert!(query_joins.merge_joins(joins, &mut merged_order_by_components).is_err());
+    assert_eq!(1, query_joins.len());
+    assert_eq!("component_type", merged_order_by_components[0].table_name.as_ref());
+}
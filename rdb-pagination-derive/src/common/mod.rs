@@ -1,7 +1,8 @@
 use quote::ToTokens;
 use rdb_pagination_core::{Name, TableColumn, TableName};
 use syn::{
-    Expr, Ident, Lit, LitStr, Meta, MetaNameValue, Path, Token,
+    Expr, ExprTuple, GenericArgument, Ident, Lit, LitStr, Meta, MetaNameValue, Path, PathArguments,
+    PathSegment, Token, Type, UnOp,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
 };
@@ -46,22 +47,109 @@ pub(crate) struct OrderByOption {
     pub(crate) unique:              bool,
     /// `Some(true)` means **NULL FIRST**; `Some(false)` means **NULL LAST**.
     pub(crate) nulls_first_or_last: Option<bool>,
+    /// The priority of an `Option<OrderType>` field.
+    pub(crate) priority:            Option<(usize, proc_macro2::TokenStream)>,
+    /// The value of the `OrderMethod` of an option which is always applied.
+    pub(crate) fixed:               Option<(isize, proc_macro2::TokenStream)>,
+    /// The capability which is required to use the option.
+    pub(crate) requires:            Option<String>,
     pub(crate) tokens:              proc_macro2::TokenStream,
 }
 
 impl Parse for OrderByOption {
     #[inline]
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+        let all_args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+
+        let tokens = all_args.to_token_stream();
+
+        let mut args = Vec::with_capacity(all_args.len());
+        let mut priority = None;
+        let mut fixed = None;
+        let mut requires = None;
+
+        for arg in all_args {
+            if let Expr::Assign(assign) = &arg {
+                if let Expr::Path(path) = assign.left.as_ref()
+                    && let Some(ident) = path.path.get_ident()
+                {
+                    match ident.to_string().as_str() {
+                        "priority" => {
+                            if priority.is_some() || fixed.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    arg,
+                                    "`priority` or `fixed` has been set",
+                                ));
+                            }
+
+                            priority = Some(expr_2_priority(&assign.right)?);
+
+                            continue;
+                        },
+                        "fixed" => {
+                            if priority.is_some() || fixed.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    arg,
+                                    "`priority` or `fixed` has been set",
+                                ));
+                            }
+
+                            if requires.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    arg,
+                                    "`fixed` cannot be used with `requires`",
+                                ));
+                            }
+
+                            fixed = Some(expr_2_fixed(&assign.right)?);
+
+                            continue;
+                        },
+                        "requires" => {
+                            if requires.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    arg,
+                                    "`requires` has been set",
+                                ));
+                            }
+
+                            if fixed.is_some() {
+                                return Err(syn::Error::new_spanned(
+                                    arg,
+                                    "`requires` cannot be used with `fixed`",
+                                ));
+                            }
+
+                            requires = Some(expr_2_string(&assign.right)?);
+
+                            continue;
+                        },
+                        _ => (),
+                    }
+                }
+
+                return Err(syn::Error::new_spanned(
+                    &assign.left,
+                    "expected `priority`, `fixed` or `requires`",
+                ));
+            }
+
+            if priority.is_some() || fixed.is_some() || requires.is_some() {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "positional arguments should be put before `priority`, `fixed` and `requires`",
+                ));
+            }
+
+            args.push(arg);
+        }
 
         let args_len = args.len();
 
         if !(1..=3).contains(&args_len) {
-            return Err(syn::Error::new_spanned(args, "expected 1, 2 or 3 arguments"));
+            return Err(syn::Error::new_spanned(tokens, "expected 1, 2 or 3 arguments"));
         }
 
-        let tokens = args.to_token_stream();
-
         let table_column = expr_2_two_string_tuple(&args[0])?;
 
         let (unique, nulls_first_or_last) = match args_len {
@@ -85,11 +173,45 @@ impl Parse for OrderByOption {
             table_column: (Name::Dynamic(table_column.0), Name::Dynamic(table_column.1)),
             unique,
             nulls_first_or_last,
+            priority,
+            fixed,
+            requires,
             tokens,
         })
     }
 }
 
+#[inline]
+fn expr_2_priority(expr: &Expr) -> syn::Result<(usize, proc_macro2::TokenStream)> {
+    if let Expr::Lit(lit) = expr
+        && let Lit::Int(lit) = &lit.lit
+        && let Ok(priority) = lit.base10_parse::<usize>()
+        && priority > 0
+    {
+        return Ok((priority, lit.to_token_stream()));
+    }
+
+    Err(syn::Error::new_spanned(expr, "expected a positive integer"))
+}
+
+#[inline]
+fn expr_2_fixed(expr: &Expr) -> syn::Result<(isize, proc_macro2::TokenStream)> {
+    let (lit, negative) = match expr {
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => (unary.expr.as_ref(), true),
+        _ => (expr, false),
+    };
+
+    if let Expr::Lit(lit) = lit
+        && let Lit::Int(lit) = &lit.lit
+        && let Ok(value) = lit.base10_parse::<isize>()
+        && value != 0
+    {
+        return Ok((if negative { -value } else { value }, expr.to_token_stream()));
+    }
+
+    Err(syn::Error::new_spanned(expr, "expected a non-zero integer"))
+}
+
 #[inline]
 pub(crate) fn path_to_string(path: &Path) -> String {
     path.into_token_stream().to_string().replace(' ', "")
@@ -204,3 +326,148 @@ pub(crate) fn meta_2_string(meta: &Meta) -> syn::Result<String> {
         format!("expected `{path} = Ident` or `{path}(Ident)`", path = path_to_string(meta.path())),
     ))
 }
+
+/// How a field carries its `OrderMethod`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum CarrierType {
+    /// `OrderMethod<T>`
+    OrderMethod,
+    /// `Option<OrderMethod<T>>`, where `None` means **disabled**.
+    OptionOrderMethod,
+    /// `Option<OrderType>` with a `priority`, where `None` means **disabled**.
+    OptionOrderType,
+}
+
+#[inline]
+fn type_2_last_segment(ty: &Type) -> Option<&PathSegment> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        Type::Group(group) => type_2_last_segment(&group.elem),
+        Type::Paren(paren) => type_2_last_segment(&paren.elem),
+        _ => None,
+    }
+}
+
+#[inline]
+fn segment_2_type_argument(segment: &PathSegment) -> Option<&Type> {
+    if let PathArguments::AngleBracketed(arguments) = &segment.arguments
+        && arguments.args.len() == 1
+        && let Some(GenericArgument::Type(ty)) = arguments.args.first()
+    {
+        Some(ty)
+    } else {
+        None
+    }
+}
+
+/// Find out how a field type carries its `OrderMethod`. The type of the value of the `OrderMethod` is also returned, which is `None` if it is omitted or the field type is `Option<OrderType>`.
+pub(crate) fn type_2_carrier_type(ty: &Type) -> syn::Result<(CarrierType, Option<&Type>)> {
+    if let Some(segment) = type_2_last_segment(ty) {
+        if segment.ident == "OrderMethod" {
+            match &segment.arguments {
+                PathArguments::None => return Ok((CarrierType::OrderMethod, None)),
+                _ => {
+                    if let Some(value_type) = segment_2_type_argument(segment) {
+                        return Ok((CarrierType::OrderMethod, Some(value_type)));
+                    }
+                },
+            }
+        } else if segment.ident == "Option"
+            && let Some(inner) = segment_2_type_argument(segment)
+            && let Some(inner_segment) = type_2_last_segment(inner)
+        {
+            if inner_segment.ident == "OrderType" && inner_segment.arguments.is_none() {
+                return Ok((CarrierType::OptionOrderType, None));
+            }
+
+            if inner_segment.ident == "OrderMethod" {
+                match &inner_segment.arguments {
+                    PathArguments::None => return Ok((CarrierType::OptionOrderMethod, None)),
+                    _ => {
+                        if let Some(value_type) = segment_2_type_argument(inner_segment) {
+                            return Ok((CarrierType::OptionOrderMethod, Some(value_type)));
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    Err(syn::Error::new_spanned(
+        ty,
+        "unsupported field type; expected `OrderMethod<T>`, `Option<OrderMethod<T>>`, or \
+         `Option<OrderType>` with `priority = N`",
+    ))
+}
+
+/// A column of a `#[preset(...)]` attribute, like `(table, column) unique nulls_last desc`.
+#[derive(Debug)]
+pub(crate) struct PresetColumn {
+    pub(crate) table_column:        TableColumn,
+    pub(crate) unique:              bool,
+    /// `Some(true)` means **NULL FIRST**; `Some(false)` means **NULL LAST**.
+    pub(crate) nulls_first_or_last: Option<bool>,
+    pub(crate) desc:                bool,
+    pub(crate) tokens:              proc_macro2::TokenStream,
+}
+
+impl Parse for PresetColumn {
+    #[inline]
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut tokens = proc_macro2::TokenStream::new();
+
+        let tuple: ExprTuple = input.parse()?;
+
+        tuple.to_tokens(&mut tokens);
+
+        let table_column = expr_2_two_string_tuple(&Expr::Tuple(tuple))?;
+
+        let mut unique = false;
+        let mut nulls_first_or_last = None;
+        let mut desc = None;
+
+        while !input.is_empty() && !input.peek(Token![,]) {
+            let ident: Ident = input.parse()?;
+
+            ident.to_tokens(&mut tokens);
+
+            match ident.to_string().as_str() {
+                "unique" if !unique => unique = true,
+                "nulls_first" if nulls_first_or_last.is_none() => nulls_first_or_last = Some(true),
+                "nulls_last" if nulls_first_or_last.is_none() => nulls_first_or_last = Some(false),
+                "asc" if desc.is_none() => desc = Some(false),
+                "desc" if desc.is_none() => desc = Some(true),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        "expected `unique`, `nulls_first`, `nulls_last`, `asc` or `desc`, each at \
+                         most once",
+                    ));
+                },
+            }
+        }
+
+        Ok(Self {
+            table_column: (Name::Dynamic(table_column.0), Name::Dynamic(table_column.1)),
+            unique,
+            nulls_first_or_last,
+            desc: desc.unwrap_or(false),
+            tokens,
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn meta_2_usize(meta: &Meta) -> syn::Result<usize> {
+    if let Meta::NameValue(name_value) = meta
+        && let Expr::Lit(lit) = &name_value.value
+        && let Lit::Int(lit) = &lit.lit
+    {
+        return lit.base10_parse();
+    }
+
+    Err(syn::Error::new_spanned(
+        meta,
+        format!("expected `{path} = N`", path = path_to_string(meta.path())),
+    ))
+}
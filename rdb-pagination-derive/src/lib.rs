@@ -9,12 +9,17 @@ The provided crate offers a procedural macro for defining `OrderByOptions`. See
 mod common;
 mod panic;
 
-use common::{Join, meta_2_string};
+use common::{
+    CarrierType, Join, PresetColumn, expr_2_two_string_tuple, meta_2_string, meta_2_usize,
+    type_2_carrier_type,
+};
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{ToTokens, format_ident, quote};
 use rdb_pagination_core::{Name, OrderBuilder, Relationship};
 use syn::{
-    Data, DeriveInput, Index, Meta, Token,
+    Attribute, Data, DataEnum, DeriveInput, Field, Fields, Generics, Ident, Index, Meta, Token,
+    Type, Visibility,
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
@@ -24,7 +29,14 @@ use crate::common::OrderByOption;
 
 fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let mut table_name = None;
+    let mut alias = None;
     let mut join_list = Vec::new();
+    let mut tiebreaker = None;
+    let mut allow_non_unique = false;
+    let mut out_of_range = None;
+    let mut fixed_columns = Vec::new();
+    let mut max_priority = None;
+    let mut max_fields = None;
 
     for attr in ast.attrs.iter() {
         let path = attr.path();
@@ -51,6 +63,16 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
 
                                 table_name = Some(name);
                             },
+                            "alias" => {
+                                if alias.is_some() {
+                                    return Err(syn::Error::new_spanned(
+                                        ident,
+                                        "`alias` has been set",
+                                    ));
+                                }
+
+                                alias = Some((meta_2_string(&meta)?, meta.to_token_stream()));
+                            },
                             "join" => match meta {
                                 Meta::List(list) => {
                                     let join: Join = list.parse_args()?;
@@ -64,6 +86,104 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
                                     ));
                                 },
                             },
+                            "tiebreaker" => {
+                                if tiebreaker.is_some() {
+                                    return Err(syn::Error::new_spanned(
+                                        ident,
+                                        "`tiebreaker` has been set",
+                                    ));
+                                }
+
+                                let table_column = match &meta {
+                                    Meta::NameValue(name_value) => {
+                                        expr_2_two_string_tuple(&name_value.value)?
+                                    },
+                                    meta => {
+                                        return Err(syn::Error::new_spanned(
+                                            meta,
+                                            "expected `tiebreaker = (table, column)`",
+                                        ));
+                                    },
+                                };
+
+                                tiebreaker = Some((table_column, meta.to_token_stream()));
+                            },
+                            "allow_non_unique" => {
+                                if allow_non_unique {
+                                    return Err(syn::Error::new_spanned(
+                                        ident,
+                                        "`allow_non_unique` has been set",
+                                    ));
+                                }
+
+                                if !matches!(meta, Meta::Path(_)) {
+                                    return Err(syn::Error::new_spanned(
+                                        meta,
+                                        "`allow_non_unique` does not take a value",
+                                    ));
+                                }
+
+                                allow_non_unique = true;
+                            },
+                            "column" => match meta {
+                                Meta::List(list) => {
+                                    let option: OrderByOption = list.parse_args()?;
+
+                                    if option.fixed.is_none() {
+                                        return Err(syn::Error::new_spanned(
+                                            list,
+                                            "`column` needs `fixed = N`",
+                                        ));
+                                    }
+
+                                    fixed_columns.push(option);
+                                },
+                                meta => {
+                                    return Err(syn::Error::new_spanned(
+                                        meta,
+                                        "`column` should be a list",
+                                    ));
+                                },
+                            },
+                            "max_priority" | "max_fields" => {
+                                let (limit, name) = if ident == "max_priority" {
+                                    (&mut max_priority, "max_priority")
+                                } else {
+                                    (&mut max_fields, "max_fields")
+                                };
+
+                                if limit.is_some() {
+                                    return Err(syn::Error::new_spanned(
+                                        ident,
+                                        format!("`{name}` has been set"),
+                                    ));
+                                }
+
+                                *limit = Some(meta_2_usize(&meta)?);
+                            },
+                            "out_of_range" => {
+                                if out_of_range.is_some() {
+                                    return Err(syn::Error::new_spanned(
+                                        ident,
+                                        "`out_of_range` has been set",
+                                    ));
+                                }
+
+                                let policy = match meta_2_string(&meta)?.as_str() {
+                                    "clamp" => quote! { Clamp },
+                                    "empty" => quote! { Empty },
+                                    "error" => quote! { Error },
+                                    _ => {
+                                        return Err(syn::Error::new_spanned(
+                                            meta,
+                                            "expected `out_of_range = clamp`, `out_of_range = \
+                                             empty` or `out_of_range = error`",
+                                        ));
+                                    },
+                                };
+
+                                out_of_range = Some(policy);
+                            },
                             _ => {
                                 return Err(panic::sub_attributes_for_item(path));
                             },
@@ -82,8 +202,29 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     }
 
     let mut token_stream = proc_macro2::TokenStream::new();
+    // Whether the ordering may not be deterministic, i.e. there is no tiebreaker and some ordering lacks a unique column of the base table.
+    let mut non_deterministic = false;
 
     if let Some(table_name) = table_name {
+        let tiebreaker = if let Some(((tiebreaker_table_name, column_name), tokens)) = tiebreaker {
+            if tiebreaker_table_name != table_name {
+                return Err(syn::Error::new_spanned(
+                    tokens,
+                    "`tiebreaker` should be a column of the base table",
+                ));
+            }
+
+            Some(column_name)
+        } else {
+            None
+        };
+
+        let out_of_range_impl = out_of_range.map(|policy| {
+            quote! {
+                const OUT_OF_RANGE_POLICY: rdb_pagination_prelude::OutOfRangePolicy = rdb_pagination_prelude::OutOfRangePolicy::#policy;
+            }
+        });
+
         let mut relationship = Relationship::new(Name::Dynamic(table_name.clone()));
 
         for join in join_list.iter() {
@@ -96,12 +237,92 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
             }
         }
 
-        if let Data::Struct(data) = ast.data {
+        let mut join_impl = proc_macro2::TokenStream::new();
+
+        if let Some((alias, tokens)) = &alias {
+            if join_list.iter().any(|join| join.primary.0.as_ref() == alias) {
+                return Err(syn::Error::new_spanned(
+                    tokens,
+                    "`alias` should not be the name of a joined table",
+                ));
+            }
+
+            join_impl.extend(quote! {
+                relationship.set_alias(rdb_pagination_prelude::Name::Static(#alias));
+            });
+        }
+
+        for join in join_list.iter() {
+            let foreign_table_name = join.foreign.0.as_ref();
+            let foreign_column_name = join.foreign.1.as_ref();
+            let primary_table_name = join.primary.0.as_ref();
+            let primary_column_name = join.primary.1.as_ref();
+
+            let real_table_name = if let Some(real_table_name) = &join.real_table_name {
+                let real_table_name = real_table_name.as_ref();
+
+                quote!(Some(rdb_pagination_prelude::Name::Static(#real_table_name)))
+            } else {
+                quote!(None)
+            };
+
+            join_impl.extend(quote! {
+                relationship.join(
+                    (rdb_pagination_prelude::Name::Static(#foreign_table_name), rdb_pagination_prelude::Name::Static(#foreign_column_name)),
+                    (rdb_pagination_prelude::Name::Static(#primary_table_name), rdb_pagination_prelude::Name::Static(#primary_column_name)),
+                    #real_table_name
+                );
+            });
+        }
+
+        if let Data::Enum(data) = &ast.data {
+            if let Some(column) = fixed_columns.first() {
+                return Err(syn::Error::new_spanned(
+                    &column.tokens,
+                    "`column` cannot be used with presets",
+                ));
+            }
+
+            let (presets_token_stream, has_unique_base_column_in_all) = presets_impl(
+                &ast,
+                data,
+                relationship,
+                &table_name,
+                &join_impl,
+                tiebreaker.as_deref(),
+                out_of_range_impl.as_ref(),
+            )?;
+
+            token_stream.extend(presets_token_stream);
+
+            non_deterministic = tiebreaker.is_none() && !has_unique_base_column_in_all;
+        } else if let Data::Struct(data) = ast.data {
             let mut options = Vec::with_capacity(data.fields.len());
+            let mut fixed_options = Vec::with_capacity(fixed_columns.len());
+            let mut has_unique_base_column = false;
+            // The type of the value of the `OrderMethod` of each field except `Option<OrderType>` ones, and the type of the field.
+            let mut value_types: Vec<(String, proc_macro2::TokenStream, &Type)> = Vec::new();
 
             {
                 let mut order_builder: OrderBuilder<i16> =
-                    OrderBuilder::new(relationship, data.fields.len());
+                    OrderBuilder::new(relationship, data.fields.len() + fixed_columns.len());
+
+                let mut check_option = |order_by_option: &OrderByOption| {
+                    if let Err(error) = order_builder.add_order_option_check(
+                        order_by_option.table_column.clone(),
+                        order_by_option.unique,
+                    ) {
+                        return Err(syn::Error::new_spanned(&order_by_option.tokens, error));
+                    }
+
+                    if order_by_option.unique
+                        && order_by_option.table_column.0.as_ref() == table_name
+                    {
+                        has_unique_base_column = true;
+                    }
+
+                    Ok(())
+                };
 
                 for (index, field) in data.fields.iter().enumerate() {
                     let mut has_option = false;
@@ -119,125 +340,891 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
 
                             let order_by_option: OrderByOption = attr.parse_args()?;
 
-                            if let Err(error) = order_builder.add_order_option_check(
-                                order_by_option.table_column.clone(),
-                                order_by_option.unique,
-                            ) {
-                                return Err(syn::Error::new_spanned(
-                                    &order_by_option.tokens,
-                                    error,
-                                ));
+                            has_option = true;
+
+                            // The value of a field with a fixed option is ignored, so it can be a unit or a skipped field.
+                            if order_by_option.fixed.is_some() {
+                                check_option(&order_by_option)?;
+
+                                let field_name = match &field.ident {
+                                    Some(ident) => ident.to_string(),
+                                    None => index.to_string(),
+                                };
+
+                                fixed_options.push((Some(field_name), order_by_option));
+
+                                continue;
                             }
 
-                            has_option = true;
+                            let (carrier_type, field_value_type) = type_2_carrier_type(&field.ty)?;
 
-                            options.push((index, field, order_by_option));
+                            match (carrier_type, &order_by_option.priority) {
+                                (CarrierType::OptionOrderType, None) => {
+                                    return Err(syn::Error::new_spanned(
+                                        &field.ty,
+                                        "an `Option<OrderType>` field needs `priority = N`",
+                                    ));
+                                },
+                                (CarrierType::OptionOrderType, Some(_)) => (),
+                                (_, Some((_, lit))) => {
+                                    return Err(syn::Error::new_spanned(
+                                        lit,
+                                        "`priority` can only be used with an `Option<OrderType>` \
+                                         field",
+                                    ));
+                                },
+                                (_, None) => (),
+                            }
+
+                            let field_value_type = if carrier_type == CarrierType::OptionOrderType {
+                                None
+                            } else {
+                                // An omitted one is `i8`, the default of `OrderMethod`.
+                                let tokens = match field_value_type {
+                                    Some(ty) => ty.to_token_stream(),
+                                    None => quote!(i8),
+                                };
+
+                                let value_type = tokens.to_string();
+
+                                value_types.push((value_type.clone(), tokens, &field.ty));
+
+                                Some(value_type)
+                            };
+
+                            check_option(&order_by_option)?;
+
+                            options.push((
+                                index,
+                                field,
+                                order_by_option,
+                                carrier_type,
+                                field_value_type,
+                            ));
+                        }
+                    }
+                }
+
+                for order_by_option in fixed_columns {
+                    check_option(&order_by_option)?;
+
+                    fixed_options.push((None, order_by_option));
+                }
+            }
+            // The type of the values of `OrderMethod`s used by `OrderBuilder`. If fields have different integer types, use the widest one, to which the others are converted. If there are only `Option<OrderType>` fields, use `i16`.
+            let value_type = match value_types.first() {
+                Some((first, tokens, _)) => {
+                    if let Some((_, _, ty)) =
+                        value_types.iter().find(|(value_type, ..)| value_type != first)
+                    {
+                        if value_types
+                            .iter()
+                            .all(|(value_type, ..)| value_type_max(value_type).is_some())
+                        {
+                            value_types
+                                .iter()
+                                .max_by_key(|(value_type, ..)| value_type_max(value_type))
+                                .map(|(value_type, tokens, _)| (value_type.clone(), tokens.clone()))
+                                .unwrap()
+                        } else {
+                            return Err(syn::Error::new_spanned(
+                                ty,
+                                format!(
+                                    "the value type of this `OrderMethod` is not `{first}`; only \
+                                     built-in integer types can be mixed"
+                                ),
+                            ));
+                        }
+                    } else {
+                        (first.clone(), tokens.clone())
+                    }
+                },
+                None => (String::from("i16"), quote!(i16)),
+            };
+
+            // A built-in integer type is widened further if it cannot hold the `priority` and `fixed` values.
+            let value_type = match value_type_max(&value_type.0) {
+                Some(max_value) => {
+                    let needed_value = options
+                        .iter()
+                        .filter_map(|(_, _, option, ..)| option.priority.as_ref())
+                        .map(|(priority, _)| *priority)
+                        .chain(
+                            fixed_options
+                                .iter()
+                                .filter_map(|(_, option)| option.fixed.as_ref())
+                                .map(|(fixed, _)| fixed.unsigned_abs()),
+                        )
+                        .max()
+                        .unwrap_or(0);
+
+                    if needed_value > max_value {
+                        match ["i16", "i32", "i64"].into_iter().find(|value_type| {
+                            value_type_max(value_type).is_some_and(|max| max >= needed_value)
+                        }) {
+                            Some(value_type) => (
+                                String::from(value_type),
+                                format_ident!("{value_type}").into_token_stream(),
+                            ),
+                            None => value_type,
                         }
+                    } else {
+                        value_type
+                    }
+                },
+                None => value_type,
+            };
+
+            let max_value = value_type_max(&value_type.0);
+            let value_type_name = value_type.0;
+            let value_type = value_type.1;
+
+            if let Some(max_value) = max_value {
+                for (_, _, option, ..) in options.iter() {
+                    if let Some((priority, tokens)) = &option.priority
+                        && *priority > max_value
+                    {
+                        return Err(syn::Error::new_spanned(
+                            tokens,
+                            format!("`priority` should not be bigger than {max_value}"),
+                        ));
+                    }
+                }
+
+                for (_, option) in fixed_options.iter() {
+                    if let Some((fixed, tokens)) = &option.fixed
+                        && fixed.unsigned_abs() > max_value
+                    {
+                        return Err(syn::Error::new_spanned(
+                            tokens,
+                            format!(
+                                "the absolute value of `fixed` should not be bigger than \
+                                 {max_value}; use a wider value type for `OrderMethod` for a \
+                                 bigger one"
+                            ),
+                        ));
                     }
                 }
             }
+
             // Get the identifier of the type.
             let name = &ast.ident;
 
-            let options_len = options.len();
+            let options_len = options.len() + fixed_options.len();
 
             if options_len == 0 {
+                let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
                 token_stream.extend(quote! {
-                    impl OrderByOptions for #name {}
+                    impl #impl_generics OrderByOptions for #name #ty_generics #where_clause {
+                        #out_of_range_impl
+                    }
                 });
             } else {
-                let mut join_impl = proc_macro2::TokenStream::new();
+                let mut options_impl = proc_macro2::TokenStream::new();
+                // The statements which add options for `to_sql_with`, where the options requiring capabilities are checked against `policy`.
+                let mut policy_options_impl = proc_macro2::TokenStream::new();
+                let mut required_capabilities = Vec::new();
+                let mut enum_fields = Vec::with_capacity(options_len);
 
-                for join in join_list {
-                    let foreign_table_name = join.foreign.0.as_ref();
-                    let foreign_column_name = join.foreign.1.as_ref();
-                    let primary_table_name = join.primary.0.as_ref();
-                    let primary_column_name = join.primary.1.as_ref();
+                for (index, field, option, carrier_type, field_value_type) in options {
+                    let table_name = option.table_column.0.as_ref();
+                    let column_name = option.table_column.1.as_ref();
+                    let unique = option.unique;
 
-                    let real_table_name = if let Some(real_table_name) = join.real_table_name {
-                        let real_table_name = real_table_name.as_ref();
+                    let null_strategy = null_strategy_impl(option.nulls_first_or_last);
 
-                        quote!(Some(rdb_pagination_prelude::Name::Static(#real_table_name)))
-                    } else {
-                        quote!(None)
+                    let field_name = match &field.ident {
+                        Some(ident) => ident.to_string(),
+                        None => index.to_string(),
                     };
 
-                    join_impl.extend(quote! {
-                        relationship.join(
-                            (rdb_pagination_prelude::Name::Static(#foreign_table_name), rdb_pagination_prelude::Name::Static(#foreign_column_name)),
-                            (rdb_pagination_prelude::Name::Static(#primary_table_name), rdb_pagination_prelude::Name::Static(#primary_column_name)),
-                            #real_table_name
+                    let enum_field = EnumField::new(
+                        index,
+                        field,
+                        carrier_type,
+                        option.priority.map(|(priority, _)| priority),
+                        field_value_type.is_some_and(|ty| ty != value_type_name),
+                    );
+
+                    let order_method = enum_field.order_method(&value_type);
+
+                    let add_option_impl = quote! {
+                        order_builder.add_named_order_option(
+                            rdb_pagination_prelude::Name::Static(#field_name),
+                            (rdb_pagination_prelude::Name::Static(#table_name), rdb_pagination_prelude::Name::Static(#column_name)),
+                            #unique,
+                            #null_strategy,
+                            #order_method,
                         );
-                    });
-                }
+                    };
 
-                let mut options_impl = proc_macro2::TokenStream::new();
+                    if let Some(capability) = &option.requires {
+                        let name = &enum_field.name;
+
+                        policy_options_impl.extend(quote! {
+                            if policy.check(#name, #capability, #order_method.order_type().is_some())? {
+                                #add_option_impl
+                            }
+                        });
+
+                        required_capabilities.push(quote!((#name, #capability)));
+                    } else {
+                        policy_options_impl.extend(add_option_impl.clone());
+                    }
+
+                    options_impl.extend(add_option_impl);
 
-                for (index, field, option) in options {
+                    enum_fields.push(enum_field);
+                }
+
+                for (field_name, option) in fixed_options {
                     let table_name = option.table_column.0.as_ref();
                     let column_name = option.table_column.1.as_ref();
                     let unique = option.unique;
+                    let null_strategy = null_strategy_impl(option.nulls_first_or_last);
+                    let fixed = option
+                        .fixed
+                        .map(|(fixed, _)| proc_macro2::Literal::isize_unsuffixed(fixed));
 
-                    let null_strategy =
-                        if let Some(nulls_first_or_last) = option.nulls_first_or_last {
-                            if nulls_first_or_last {
-                                quote!(rdb_pagination_prelude::NullStrategy::First)
-                            } else {
-                                quote!(rdb_pagination_prelude::NullStrategy::Last)
-                            }
-                        } else {
-                            quote!(rdb_pagination_prelude::NullStrategy::Default)
-                        };
+                    let table_column = quote! {
+                        (rdb_pagination_prelude::Name::Static(#table_name), rdb_pagination_prelude::Name::Static(#column_name))
+                    };
+                    let order_method = quote! {
+                        rdb_pagination_prelude::OrderMethod::<#value_type>(#fixed)
+                    };
 
-                    let order_method = if let Some(ident) = &field.ident {
-                        quote!(self.#ident)
+                    let add_option_impl = if let Some(field_name) = field_name {
+                        quote! {
+                            order_builder.add_named_order_option(
+                                rdb_pagination_prelude::Name::Static(#field_name),
+                                #table_column,
+                                #unique,
+                                #null_strategy,
+                                #order_method,
+                            );
+                        }
                     } else {
-                        let index = Index::from(index);
-
-                        quote!(self.#index)
+                        quote! {
+                            order_builder.add_order_option(
+                                #table_column,
+                                #unique,
+                                #null_strategy,
+                                #order_method,
+                            );
+                        }
                     };
 
-                    options_impl.extend(quote! {
-                        order_builder.add_order_option(
-                            (rdb_pagination_prelude::Name::Static(#table_name), rdb_pagination_prelude::Name::Static(#column_name)),
-                            #unique,
-                            #null_strategy,
-                            #order_method,
-                        );
-                    });
+                    policy_options_impl.extend(add_option_impl.clone());
+                    options_impl.extend(add_option_impl);
                 }
 
-                let order_by_options_impl = quote! {
-                    impl OrderByOptions for #name {
-                        fn to_sql(&self) -> (::std::vec::Vec<rdb_pagination_prelude::SqlJoin>, ::std::vec::Vec<rdb_pagination_prelude::SqlOrderByComponent>) {
-                            let mut relationship = rdb_pagination_prelude::Relationship::new(rdb_pagination_prelude::Name::Static(#table_name));
+                let tiebreaker_impl = if let Some(column_name) = &tiebreaker {
+                    quote! {
+                        order_builder.set_tiebreaker(rdb_pagination_prelude::Name::Static(#column_name));
+                    }
+                } else {
+                    non_deterministic = !has_unique_base_column;
 
-                            #join_impl
+                    proc_macro2::TokenStream::new()
+                };
 
-                            let mut order_builder = rdb_pagination_prelude::OrderBuilder::new(relationship, #options_len);
+                let order_builder_impl = |options_impl: &proc_macro2::TokenStream| {
+                    quote! {
+                        let mut relationship = rdb_pagination_prelude::Relationship::new(rdb_pagination_prelude::Name::Static(#table_name));
 
-                            #options_impl
+                        #join_impl
 
-                            order_builder.build()
-                        }
+                        let mut order_builder = rdb_pagination_prelude::OrderBuilder::<#value_type>::new(relationship, #options_len);
+
+                        #options_impl
+
+                        #tiebreaker_impl
                     }
                 };
 
-                token_stream.extend(order_by_options_impl);
+                let mut items_impl = out_of_range_impl.unwrap_or_default();
+
+                if !required_capabilities.is_empty() {
+                    let policy_order_builder_impl = order_builder_impl(&policy_options_impl);
+
+                    items_impl.extend(quote! {
+                        const REQUIRED_CAPABILITIES: &'static [(&'static str, &'static str)] = &[#(#required_capabilities),*];
+
+                        fn to_sql_with<F: ::core::ops::Fn(&str) -> bool>(&self, policy: &rdb_pagination_prelude::AccessPolicy<F>) -> ::core::result::Result<(::std::vec::Vec<rdb_pagination_prelude::SqlJoin>, ::std::vec::Vec<rdb_pagination_prelude::SqlOrderByComponent>), rdb_pagination_prelude::AccessDeniedError> {
+                            #policy_order_builder_impl
+
+                            ::core::result::Result::Ok(order_builder.build())
+                        }
+
+                        fn to_partitioned_sql_with<F: ::core::ops::Fn(&str) -> bool>(&self, partition_by: ::std::vec::Vec<rdb_pagination_prelude::TableColumn>, policy: &rdb_pagination_prelude::AccessPolicy<F>) -> ::core::result::Result<(::std::vec::Vec<rdb_pagination_prelude::SqlJoin>, ::std::vec::Vec<rdb_pagination_prelude::TableColumn>, ::std::vec::Vec<rdb_pagination_prelude::SqlOrderByComponent>), rdb_pagination_prelude::AccessDeniedError> {
+                            #policy_order_builder_impl
+
+                            for table_column in partition_by {
+                                order_builder.add_partition_column(table_column);
+                            }
+
+                            ::core::result::Result::Ok(order_builder.build_partitioned())
+                        }
+
+                        fn explain_with<F: ::core::ops::Fn(&str) -> bool>(&self, policy: &rdb_pagination_prelude::AccessPolicy<F>) -> ::core::result::Result<rdb_pagination_prelude::OrderByExplanation, rdb_pagination_prelude::AccessDeniedError> {
+                            #policy_order_builder_impl
+
+                            ::core::result::Result::Ok(order_builder.explain())
+                        }
+                    });
+                }
+
+                if !enum_fields.is_empty() {
+                    items_impl.extend(validate_impl(
+                        &value_type,
+                        &enum_fields,
+                        max_priority,
+                        max_fields,
+                    ));
+                }
+
+                token_stream.extend(order_by_options_impl(
+                    name,
+                    &ast.generics,
+                    Some(&items_impl),
+                    &order_builder_impl(&options_impl),
+                ));
+                if !enum_fields.is_empty() {
+                    token_stream.extend(order_by_fields_impl(
+                        name,
+                        &ast.generics,
+                        &ast.vis,
+                        &value_type,
+                        &enum_fields,
+                    ));
+                }
             }
         } else {
             return Err(syn::Error::new_spanned(
                 ast.ident,
-                "should use a struct to implement `OrderByOptions`",
+                "should use a struct or an enum to implement `OrderByOptions`",
             ));
         }
     }
 
+    if non_deterministic && !allow_non_unique {
+        token_stream.extend(no_unique_column_warning(&ast.ident));
+    }
+
     Ok(token_stream)
 }
 
-/// Derives `OrderByOptions` for a struct whose fields describe SQL ordering options.
-#[proc_macro_derive(OrderByOptions, attributes(orderByOptions))]
+/// Implement `OrderByOptions` with the statements which create an `OrderBuilder` named `order_builder`. `items_impl` contains additional items of the implementation.
+fn order_by_options_impl(
+    name: &Ident,
+    generics: &Generics,
+    items_impl: Option<&proc_macro2::TokenStream>,
+    order_builder_impl: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics OrderByOptions for #name #ty_generics #where_clause {
+            #items_impl
+
+            fn to_sql(&self) -> (::std::vec::Vec<rdb_pagination_prelude::SqlJoin>, ::std::vec::Vec<rdb_pagination_prelude::SqlOrderByComponent>) {
+                #order_builder_impl
+
+                order_builder.build()
+            }
+
+            fn to_partitioned_sql(&self, partition_by: ::std::vec::Vec<rdb_pagination_prelude::TableColumn>) -> (::std::vec::Vec<rdb_pagination_prelude::SqlJoin>, ::std::vec::Vec<rdb_pagination_prelude::TableColumn>, ::std::vec::Vec<rdb_pagination_prelude::SqlOrderByComponent>) {
+                #order_builder_impl
+
+                for table_column in partition_by {
+                    order_builder.add_partition_column(table_column);
+                }
+
+                order_builder.build_partitioned()
+            }
+
+            fn explain(&self) -> rdb_pagination_prelude::OrderByExplanation {
+                #order_builder_impl
+
+                order_builder.explain()
+            }
+        }
+    }
+}
+
+/// Implement `OrderByOptions` for an enum whose unit variants are ordering presets, each of which lists its columns in `#[preset(...)]`. Also returns whether every preset has a unique column of the base table.
+fn presets_impl(
+    ast: &DeriveInput,
+    data: &DataEnum,
+    relationship: Relationship,
+    table_name: &str,
+    join_impl: &proc_macro2::TokenStream,
+    tiebreaker: Option<&str>,
+    out_of_range_impl: Option<&proc_macro2::TokenStream>,
+) -> syn::Result<(proc_macro2::TokenStream, bool)> {
+    let name = &ast.ident;
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+    let mut capacity = 0;
+    let mut has_unique_base_column_in_all = true;
+
+    for variant in data.variants.iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "a preset should be a unit variant",
+            ));
+        }
+
+        let mut columns = None;
+
+        for attr in variant.attrs.iter() {
+            if attr.path().is_ident("preset") {
+                if columns.is_some() {
+                    return Err(syn::Error::new_spanned(attr, "`preset` has been set"));
+                }
+
+                columns = Some(
+                    attr.parse_args_with(Punctuated::<PresetColumn, Token![,]>::parse_terminated)?,
+                );
+            }
+        }
+
+        let Some(columns) = columns else {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                "expected `#[preset((table, column) asc, ...)]`",
+            ));
+        };
+
+        let mut order_builder: OrderBuilder<i16> =
+            OrderBuilder::new(relationship.clone(), columns.len());
+        let mut has_unique_base_column = false;
+        let mut options_impl = proc_macro2::TokenStream::new();
+
+        for (index, column) in columns.iter().enumerate() {
+            if let Err(error) =
+                order_builder.add_order_option_check(column.table_column.clone(), column.unique)
+            {
+                return Err(syn::Error::new_spanned(&column.tokens, error));
+            }
+
+            if column.unique && column.table_column.0.as_ref() == table_name {
+                has_unique_base_column = true;
+            }
+
+            let table_name = column.table_column.0.as_ref();
+            let column_name = column.table_column.1.as_ref();
+            let unique = column.unique;
+            let null_strategy = null_strategy_impl(column.nulls_first_or_last);
+
+            let priority = index as i16 + 1;
+            let order_method = proc_macro2::Literal::i16_unsuffixed(if column.desc {
+                -priority
+            } else {
+                priority
+            });
+
+            options_impl.extend(quote! {
+                order_builder.add_order_option(
+                    (rdb_pagination_prelude::Name::Static(#table_name), rdb_pagination_prelude::Name::Static(#column_name)),
+                    #unique,
+                    #null_strategy,
+                    rdb_pagination_prelude::OrderMethod::<i16>(#order_method),
+                );
+            });
+        }
+
+        has_unique_base_column_in_all &= has_unique_base_column;
+        capacity = capacity.max(columns.len());
+
+        let variant_ident = &variant.ident;
+
+        arms.push(quote! {
+            Self::#variant_ident => {
+                #options_impl
+            },
+        });
+    }
+
+    let tiebreaker_impl = if let Some(column_name) = tiebreaker {
+        quote! {
+            order_builder.set_tiebreaker(rdb_pagination_prelude::Name::Static(#column_name));
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
+    let order_builder_impl = quote! {
+        let mut relationship = rdb_pagination_prelude::Relationship::new(rdb_pagination_prelude::Name::Static(#table_name));
+
+        #join_impl
+
+        let mut order_builder = rdb_pagination_prelude::OrderBuilder::<i16>::new(relationship, #capacity);
+
+        match *self {
+            #(#arms)*
+        }
+
+        #tiebreaker_impl
+    };
+
+    let token_stream =
+        order_by_options_impl(name, &ast.generics, out_of_range_impl, &order_builder_impl);
+
+    Ok((token_stream, has_unique_base_column_in_all))
+}
+
+/// Implement `OrderByOptions::validate` which checks the `OrderMethod`s of the annotated fields against the limits.
+fn validate_impl(
+    value_type: &proc_macro2::TokenStream,
+    fields: &[EnumField],
+    max_priority: Option<usize>,
+    max_fields: Option<usize>,
+) -> proc_macro2::TokenStream {
+    let max_priority = option_usize_impl(max_priority);
+    let max_fields = option_usize_impl(max_fields);
+
+    let order_methods = fields.iter().map(|field| {
+        let name = &field.name;
+        let order_method = field.order_method(value_type);
+
+        quote!((#name, #order_method))
+    });
+
+    quote! {
+        fn validate(&self) -> ::core::result::Result<(), rdb_pagination_prelude::OrderMethodError> {
+            let limits = rdb_pagination_prelude::OrderMethodLimits {
+                max_priority: #max_priority,
+                max_fields: #max_fields,
+            };
+
+            limits.validate(&[#(#order_methods),*])
+        }
+    }
+}
+
+fn option_usize_impl(value: Option<usize>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => {
+            let value = proc_macro2::Literal::usize_unsuffixed(value);
+
+            quote!(::core::option::Option::Some(#value))
+        },
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+/// The maximum value of a built-in integer type which can be used as the value type of `OrderMethod`.
+fn value_type_max(value_type: &str) -> Option<usize> {
+    match value_type {
+        "i8" => Some(i8::MAX as usize),
+        "i16" => Some(i16::MAX as usize),
+        "i32" => Some(i32::MAX as usize),
+        "i64" => Some(i64::MAX as usize),
+        _ => None,
+    }
+}
+
+fn null_strategy_impl(nulls_first_or_last: Option<bool>) -> proc_macro2::TokenStream {
+    match nulls_first_or_last {
+        Some(true) => quote!(rdb_pagination_prelude::NullStrategy::First),
+        Some(false) => quote!(rdb_pagination_prelude::NullStrategy::Last),
+        None => quote!(rdb_pagination_prelude::NullStrategy::Default),
+    }
+}
+
+/// An annotated field which becomes a variant of the generated field enum.
+struct EnumField<'a> {
+    variant:      Ident,
+    name:         String,
+    member:       proc_macro2::TokenStream,
+    docs:         Vec<&'a Attribute>,
+    carrier_type: CarrierType,
+    priority:     Option<usize>,
+    /// Whether the value of the `OrderMethod` needs to be converted to the value type used by `OrderBuilder`.
+    widen:        bool,
+}
+
+impl<'a> EnumField<'a> {
+    fn new(
+        index: usize,
+        field: &'a Field,
+        carrier_type: CarrierType,
+        priority: Option<usize>,
+        widen: bool,
+    ) -> Self {
+        let (variant, name, member) = if let Some(ident) = &field.ident {
+            let name = ident.unraw().to_string();
+
+            (format_ident!("{}", snake_case_2_pascal_case(&name)), name, quote!(#ident))
+        } else {
+            let member = Index::from(index);
+
+            (format_ident!("Field{index}"), index.to_string(), quote!(#member))
+        };
+
+        let docs = field.attrs.iter().filter(|attr| attr.path().is_ident("doc")).collect();
+
+        Self {
+            variant,
+            name,
+            member,
+            docs,
+            carrier_type,
+            priority,
+            widen,
+        }
+    }
+
+    /// An expression of the `OrderMethod` which is carried by this field of `self`.
+    fn order_method(&self, value_type: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let member = &self.member;
+
+        let order_method = match self.carrier_type {
+            CarrierType::OrderMethod => quote!(self.#member),
+            CarrierType::OptionOrderMethod => {
+                quote!(::core::option::Option::unwrap_or_default(self.#member))
+            },
+            CarrierType::OptionOrderType => {
+                let priority = self.priority.map(proc_macro2::Literal::usize_unsuffixed);
+
+                quote! {
+                    match self.#member {
+                        ::core::option::Option::Some(order_type) => rdb_pagination_prelude::OrderMethod::<#value_type>::from_priority(#priority, order_type).unwrap_or_default(),
+                        ::core::option::Option::None => rdb_pagination_prelude::OrderMethod::<#value_type>::default(),
+                    }
+                }
+            },
+        };
+
+        if self.widen {
+            quote!(rdb_pagination_prelude::OrderMethod::<#value_type>(::core::convert::From::from(#order_method.0)))
+        } else {
+            order_method
+        }
+    }
+
+    /// Statements which set this field of `options` from `order_type` in `OrderByFields::from_fields`. The priorities given to `OrderMethod`s follow the order of the list and skip `static_priorities`, which are declared by `Option<OrderType>` fields.
+    fn set_from_field(&self, static_priorities: &[usize]) -> proc_macro2::TokenStream {
+        let member = &self.member;
+
+        let next_priority = if static_priorities.is_empty() {
+            quote! {
+                priority += 1;
+            }
+        } else {
+            let static_priorities = static_priorities
+                .iter()
+                .map(|&priority| proc_macro2::Literal::usize_unsuffixed(priority));
+
+            quote! {
+                priority += 1;
+
+                while matches!(priority, #(#static_priorities)|*) {
+                    priority += 1;
+                }
+            }
+        };
+
+        match self.carrier_type {
+            CarrierType::OrderMethod => quote! {
+                #next_priority
+
+                options.#member = rdb_pagination_prelude::OrderMethod::from_priority(priority, order_type).unwrap_or_default();
+            },
+            CarrierType::OptionOrderMethod => quote! {
+                #next_priority
+
+                options.#member = rdb_pagination_prelude::OrderMethod::from_priority(priority, order_type);
+            },
+            CarrierType::OptionOrderType => quote! {
+                options.#member = ::core::option::Option::Some(order_type);
+            },
+        }
+    }
+}
+
+fn snake_case_2_pascal_case(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    let mut upper = true;
+
+    for c in s.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            output.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+/// Generate the `{Name}Field` enum and implement `OrderByFields` for the struct.
+fn order_by_fields_impl(
+    name: &Ident,
+    generics: &Generics,
+    vis: &Visibility,
+    value_type: &proc_macro2::TokenStream,
+    fields: &[EnumField],
+) -> proc_macro2::TokenStream {
+    let enum_name = format_ident!("{name}Field");
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let enum_doc = format!("Sortable fields of [`{name}`].");
+
+    let fields_len = fields.len();
+
+    let variants = fields.iter().map(|field| {
+        let variant = &field.variant;
+        let docs = &field.docs;
+
+        quote!(#(#docs)* #variant)
+    });
+    let variant_idents = fields.iter().map(|field| &field.variant).collect::<Vec<_>>();
+    let names = fields.iter().map(|field| &field.name);
+    let members = fields.iter().map(|field| &field.member).collect::<Vec<_>>();
+    let indexes = 0..fields_len;
+    let mut static_priorities =
+        fields.iter().filter_map(|field| field.priority).collect::<Vec<_>>();
+
+    static_priorities.sort_unstable();
+    static_priorities.dedup();
+
+    let set_from_fields = fields.iter().map(|field| field.set_from_field(&static_priorities));
+    let order_methods = fields.iter().map(|field| field.order_method(value_type));
+
+    let serde_impl = if cfg!(feature = "serde") {
+        quote! {
+            impl rdb_pagination_prelude::serde::Serialize for #enum_name {
+                #[inline]
+                fn serialize<S: rdb_pagination_prelude::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                    rdb_pagination_prelude::serialize_order_by_field(self, serializer)
+                }
+            }
+
+            impl<'de> rdb_pagination_prelude::serde::Deserialize<'de> for #enum_name {
+                #[inline]
+                fn deserialize<D: rdb_pagination_prelude::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                    rdb_pagination_prelude::deserialize_order_by_field(deserializer)
+                }
+            }
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
+    quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        #vis enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl rdb_pagination_prelude::OrderByField for #enum_name {
+            const FIELDS: &'static [Self] = &[#(Self::#variant_idents,)*];
+
+            #[inline]
+            fn as_str(&self) -> &'static str {
+                match *self {
+                    #(Self::#variant_idents => #names,)*
+                }
+            }
+        }
+
+        #serde_impl
+
+        impl #impl_generics rdb_pagination_prelude::OrderByFields for #name #ty_generics #where_clause {
+            type Field = #enum_name;
+
+            fn from_fields(fields: &[(#enum_name, rdb_pagination_prelude::OrderType)]) -> Self {
+                let mut options = <Self as ::core::default::Default>::default();
+
+                #(options.#members = ::core::default::Default::default();)*
+
+                let mut used = [false; #fields_len];
+                #[allow(unused_mut, unused_variables)]
+                let mut priority = 0usize;
+
+                for &(field, order_type) in fields {
+                    match field {
+                        #(
+                            #enum_name::#variant_idents => {
+                                if !used[#indexes] {
+                                    used[#indexes] = true;
+
+                                    #set_from_fields
+                                }
+                            },
+                        )*
+                    }
+                }
+
+                options
+            }
+
+            fn to_fields(&self) -> ::std::vec::Vec<(#enum_name, rdb_pagination_prelude::OrderType)> {
+                let mut fields = ::std::vec::Vec::with_capacity(#fields_len);
+
+                #(
+                    {
+                        let order_method: rdb_pagination_prelude::OrderMethod<#value_type> = #order_methods;
+
+                        if let ::core::option::Option::Some(order_type) = order_method.order_type() {
+                            fields.push((rdb_pagination_prelude::OrderMethodValue::abs(&order_method.0), #enum_name::#variant_idents, order_type));
+                        }
+                    }
+                )*
+
+                fields.sort_by_key(|&(priority, ..)| priority);
+
+                fields.into_iter().map(|(_, field, order_type)| (field, order_type)).collect()
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<::std::vec::Vec<(#enum_name, rdb_pagination_prelude::OrderType)>> for #name #ty_generics #where_clause {
+            #[inline]
+            fn from(fields: ::std::vec::Vec<(#enum_name, rdb_pagination_prelude::OrderType)>) -> Self {
+                rdb_pagination_prelude::OrderByFields::from_fields(&fields)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for ::std::vec::Vec<(#enum_name, rdb_pagination_prelude::OrderType)> #where_clause {
+            #[inline]
+            fn from(options: #name #ty_generics) -> Self {
+                rdb_pagination_prelude::OrderByFields::to_fields(&options)
+            }
+        }
+    }
+}
+
+/// Emit a compile-time warning (through a deprecated item) saying that the ordering may not be deterministic.
+fn no_unique_column_warning(name: &Ident) -> proc_macro2::TokenStream {
+    let message = format!(
+        "`{name}` has no unique column of the base table, so the order of rows may not be \
+         deterministic; add `#[orderByOptions(tiebreaker = (table, column))]` to append one, or \
+         `#[orderByOptions(allow_non_unique)]` to allow it"
+    );
+
+    let warning_ident = format_ident!("{name}HasNoUniqueColumn", span = name.span());
+
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const #warning_ident: () = ();
+
+            #warning_ident
+        };
+    }
+}
+
+/// Derives `OrderByOptions` for a struct whose fields describe SQL ordering options, or for an enum whose variants are ordering presets.
+#[proc_macro_derive(OrderByOptions, attributes(orderByOptions, preset))]
 pub fn order_by_options_derive(input: TokenStream) -> TokenStream {
     struct MyDeriveInput(proc_macro2::TokenStream);
 
@@ -26,7 +26,17 @@ pub(crate) fn sub_attributes_for_item(tokens: impl ToTokens) -> syn::Error {
         tokens,
         format!(
             "available sub-attributes for the `orderByOptions` attribute:{}",
-            DisplayStringSlice(&["name", "join"])
+            DisplayStringSlice(&[
+                "name",
+                "alias",
+                "join",
+                "tiebreaker",
+                "allow_non_unique",
+                "column",
+                "out_of_range",
+                "max_priority",
+                "max_fields",
+            ])
         ),
     )
 }
@@ -87,18 +87,426 @@ assert_eq!(
 # }
 ```
 
+After counting the rows, `PaginationOptions::to_pagination(total_items)` creates the `Pagination` for the response, and `is_out_of_range(total_items)` tells whether the requested page is after the last page, e.g. for responding with 404. `Pagination::to_options(order_by)` converts it back into an `AnyPaginationOptions`.
+
+## Offset and Limit
+
+For clients which do not fetch items page by page, such as infinite scrolling, use `OffsetPaginationOptions`, which has an arbitrary offset and an optional limit. `PaginationOptions::at_offset` converts page-based options into it, with `items_per_page` as the limit. The generated clauses use the offset as it is.
+
+```rust
+use rdb_pagination::{OffsetPaginationOptions, Pagination, PaginationOptions};
+
+let options = PaginationOptions::new().items_per_page(20).at_offset(35);
+
+assert_eq!(35, options.offset);
+assert_eq!(Some(20), options.limit);
+
+let options = OffsetPaginationOptions::new().offset(35).limit(20);
+
+assert_eq!(2, options.to_pagination(50).get_page());
+
+let pagination = Pagination::new().items_per_page(20).total_items(50).at_offset(35);
+
+assert_eq!(2, pagination.get_page());
+assert_eq!(35, pagination.get_offset());
+```
+
+`AnyPaginationOptions` is either of them, for endpoints which accept both. With the `serde` feature, it accepts both `{ "page", "items_per_page" }` and `{ "offset", "limit" }`, and a deserialized offset cannot be bigger than `PageQuery::MAX_OFFSET`.
+
+## Pager
+
+`Pagination` provides the numbers which a pager needs.
+
+```rust
+use rdb_pagination::{PageItem, Pagination};
+
+let pagination = Pagination::new().items_per_page(20).total_items(1234).page(6);
+
+// 1 … 5 [6] 7 … 62
+assert_eq!(
+    vec![
+        PageItem::Page(1),
+        PageItem::Ellipsis,
+        PageItem::Page(5),
+        PageItem::Page(6),
+        PageItem::Page(7),
+        PageItem::Ellipsis,
+        PageItem::Page(62),
+    ],
+    pagination.page_window(1, 1)
+);
+
+// Showing 101–120 of 1,234
+assert_eq!(Some(101..=120), pagination.item_range());
+
+assert_eq!(Some(7), pagination.next_page());
+assert_eq!(Some(5), pagination.prev_page());
+```
+
+## Out-of-Range Pages
+
+By default, a page number which is bigger than the total number of pages is clamped, so the last page is served instead. Choose another `OutOfRangePolicy` to keep the page number and serve an empty page (`Empty`), or to reject it (`Error`).
+
+```rust
+use rdb_pagination::{OutOfRangePolicy, Pagination};
+
+let pagination = Pagination::new()
+    .out_of_range_policy(OutOfRangePolicy::Empty)
+    .items_per_page(20)
+    .total_items(50)
+    .page(4);
+
+assert_eq!(4, pagination.get_page());
+assert!(pagination.is_out_of_range());
+```
+
+The derive takes the policy used by `PaginationOptions::to_pagination` as `out_of_range`. With `error`, use `try_to_pagination` to get a `PageOutOfRangeError`.
+
+```rust
+use rdb_pagination::{PaginationOptions, prelude::*};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = user, out_of_range = error)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod,
+}
+
+let options = PaginationOptions::<UserOrderBy>::default().page(4).items_per_page(20);
+
+assert!(options.try_to_pagination(50).is_err());
+# }
+```
+
+With the `serde` feature, a deserialized `Pagination` follows its `out_of_range_policy` member, which defaults to `clamp`. With `clamp`, a page number which is out of range is rejected instead of being clamped again, whether the member is present or not.
+
+## Field Types
+
+Besides `OrderMethod`, a field can be an `Option<OrderMethod>`, where `None` means disabled, or an `Option<OrderType>` with a fixed `priority`. Other field types are rejected by the derive.
+
+```rust
+use rdb_pagination::{OrderType, prelude::*};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, id), unique)]
+    pub id:         Option<OrderMethod>,
+    #[orderByOptions((user, created_at), priority = 1)]
+    pub created_at: Option<OrderType>,
+}
+
+let (_, order_by_components) = UserOrderBy {
+    id:         Some(OrderMethod::from(2)),
+    created_at: Some(OrderType::Desc),
+}
+.to_sql();
+
+assert_eq!(2, order_by_components.len());
+# }
+```
+
+Fields can use different built-in integer types as the value type of `OrderMethod`, such as `OrderMethod` (`i8`) and `OrderMethod<i16>`, and the values are widened to the widest one, or to a wider one which can hold the `priority` and `fixed` values. The struct can also be generic, e.g. `struct UserOrderBy<T: OrderMethodValue>` with `OrderMethod<T>` fields.
+
+`OrderMethodValue` is implemented for `i8`, `i16`, `i32` and `i64`, e.g. for clients which send sparse priorities like `1000` and `2000`. It is also implemented for `OptionNonZeroI16`, which represents the disabled state as `None` instead of `0`.
+
+## Fixed Options
+
+An option with `fixed = N` is always applied with `N` as its `OrderMethod`, so it cannot be controlled by clients. It can be put on a unit or skipped field, whose value is ignored, or on the struct as a `column`. Fixed options are not included in the `{Name}Field` enum.
+
+```rust
+use rdb_pagination::prelude::*;
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = user, column((user, id), unique, fixed = -999))]
+pub struct UserOrderBy {
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod<i16>,
+}
+
+let (_, order_by_components) = UserOrderBy::default().to_sql();
+
+assert_eq!(1, order_by_components.len());
+# }
+```
+
+The absolute value of `N` has to fit in the value type of `OrderMethod`, which is `i8` by default.
+
+## Validation
+
+The derived `validate` method rejects `OrderMethod`s whose absolute values (priorities) are duplicated, since their order would be decided by the declaration order. `max_priority = N` limits the absolute values and `max_fields = N` limits the number of enabled fields. With the `serde` feature, `PaginationOptions` calls `validate` when being deserialized.
+
+```rust
+use rdb_pagination::{prelude::*, OrderMethodError};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = user, max_priority = 2)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, id), unique)]
+    pub id:   OrderMethod,
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod,
+}
+
+let order_by = UserOrderBy {
+    id:   OrderMethod::from(1),
+    name: OrderMethod::from(-1),
+};
+
+assert_eq!(
+    Err(OrderMethodError::DuplicatePriority { priority: 1, fields: ["id", "name"] }),
+    order_by.validate()
+);
+# }
+```
+
+## Access Control
+
+An option with `requires = "capability"` can only be used by callers who have the capability. `to_sql` ignores the tag, but `to_sql_with` checks each tagged option against an `AccessPolicy`, which wraps a permission checker supplied by the caller. `AccessPolicy::drop` ignores the options the caller cannot use, and `AccessPolicy::reject` returns `AccessDeniedError` if any of them is enabled. Likewise, `to_partitioned_sql` and `explain` ignore the tag, and `to_partitioned_sql_with` and `explain_with` check it.
+
+```rust
+use rdb_pagination::{prelude::*, AccessPolicy};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, id), unique)]
+    pub id:     OrderMethod,
+    #[orderByOptions((user, salary), requires = "admin")]
+    pub salary: OrderMethod,
+}
+
+let order_by = UserOrderBy {
+    id:     OrderMethod::from(2),
+    salary: OrderMethod::from(-1),
+};
+
+let is_admin = false;
+let policy = AccessPolicy::drop(|capability: &str| capability == "admin" && is_admin);
+
+let (_, order_by_components) = order_by.to_sql_with(&policy).unwrap();
+
+assert_eq!(1, order_by_components.len());
+
+let policy = AccessPolicy::reject(|capability: &str| capability == "admin" && is_admin);
+
+assert!(order_by.to_sql_with(&policy).is_err());
+# }
+```
+
+The tagged options are listed in `OrderByOptions::REQUIRED_CAPABILITIES`. With the `utoipa` feature, the `RequiredCapabilities<T>` modifier adds them to the OpenAPI schema of `T` as the `x-required-capabilities` extension.
+
+## Presets
+
+If clients should only choose from named sort modes, derive `OrderByOptions` for an enum instead. Each unit variant lists its columns in `#[preset(...)]`. A column may be followed by `unique`, `nulls_first` or `nulls_last`, and `asc` (the default) or `desc`. The `name`, `join`, `tiebreaker` and `out_of_range` sub-attributes work as they do for a struct.
+
+```rust
+use rdb_pagination::prelude::*;
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Copy, Default, OrderByOptions)]
+#[orderByOptions(name = product)]
+pub enum ProductOrderBy {
+    #[default]
+    #[preset((product, created_at) desc, (product, id) unique desc)]
+    Newest,
+    #[preset((product, price), (product, id) unique)]
+    PriceLowToHigh,
+}
+
+let (_, order_by_components) = ProductOrderBy::PriceLowToHigh.to_sql();
+
+assert_eq!(2, order_by_components.len());
+# }
+```
+
+Derive `serde::Serialize`, `serde::Deserialize` or `utoipa::ToSchema` for the enum to expose it as a string enum.
+
+## Tiebreaker
+
+If none of the active options is a unique column of the base table, rows with equal sort keys can be returned in any order, so pages may overlap. Set `tiebreaker` to append a unique column of the base table in ascending order in that case.
+
+```rust
+use educe::Educe;
+use rdb_pagination::prelude::*;
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Educe, OrderByOptions)]
+#[educe(Default)]
+#[orderByOptions(name = user, tiebreaker = (user, id))]
+pub struct UserOrderBy {
+    #[educe(Default = 1)]
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod,
+}
+
+let (_, order_by_components) = UserOrderBy::default().to_sql();
+
+assert_eq!(2, order_by_components.len());
+# }
+```
+
+Without `tiebreaker`, the derive emits a compile-time warning (a deprecation warning) if no field is a unique column of the base table. Add `allow_non_unique`, e.g. `#[orderByOptions(name = user, allow_non_unique)]`, if the ordering does not have to be deterministic.
+
+Use `explain()` to see which options are applied, dropped or rewritten, and why each join is added.
+
+## Sort Fields
+
+The derive also generates a `{Name}Field` enum, with one variant per annotated field, and implements `OrderByFields`. Ordering options can then be converted from and to an ordered list of fields and order types, in which the first item has the highest priority. An `Option<OrderType>` field keeps its declared `priority`, and the other fields take the remaining priorities in the order of the list.
+
+```rust
+use educe::Educe;
+use rdb_pagination::{OrderType, prelude::*};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Educe, OrderByOptions)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, id), unique)]
+    pub id:         OrderMethod,
+    #[orderByOptions((user, created_at))]
+    pub created_at: OrderMethod,
+}
+
+let order_by = UserOrderBy::from(vec![
+    (UserOrderByField::CreatedAt, OrderType::Desc),
+    (UserOrderByField::Id, OrderType::Asc),
+]);
+
+assert_eq!(OrderMethod::from(-1), order_by.created_at);
+assert_eq!(OrderMethod::from(2), order_by.id);
+
+assert_eq!(Some(UserOrderByField::CreatedAt), UserOrderByField::from_name("created_at"));
+# }
+```
+
+With the `serde` feature, the field enum is serialized as the name of the field, and `OrderType` is serialized as `"asc"` or `"desc"`.
+
+## Base Table Alias
+
+If the base table is aliased in the `FROM` clause, e.g. `FROM component AS c`, set `alias` next to `name`. Every join and `ORDER BY` component which points at the base table is then rendered with the alias.
+
+```rust
+use rdb_pagination::{prelude::*, SqlOrderByComponent};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = component, alias = c)]
+pub struct ComponentOrderBy {
+    #[orderByOptions((component, id), unique)]
+    pub id: OrderMethod,
+}
+
+let order_by = ComponentOrderBy { id: OrderMethod::from(1) };
+
+let (_, order_by_components) = order_by.to_sql();
+
+let mut buffer = String::new();
+
+# #[cfg(feature = "mysql")]
+assert_eq!(
+    "ORDER BY `c`.`id` ASC",
+    SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+);
+# }
+```
+
+## Schema Qualification
+
+If each tenant keeps its tables in its own schema (or database), set the schema with `SqlJoin::schema_name`, or pass a hook to the `format_*_join_clauses_with` functions, which qualifies the joined tables without `schema_name` at runtime. A qualified table is always aliased with its unqualified name, so the `ORDER BY` clause does not change.
+
+```rust
+use rdb_pagination::{prelude::*, SqlJoin, TableName};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = component, join((component, component_type_id), (component_type, id)))]
+pub struct ComponentOrderBy {
+    #[orderByOptions((component_type, order))]
+    pub component_type_order: OrderMethod,
+}
+
+let order_by = ComponentOrderBy { component_type_order: OrderMethod::from(1) };
+
+let (joins, _) = order_by.to_sql();
+
+let tenant_id = 42;
+let qualify = |_: &SqlJoin| Some(TableName::Dynamic(format!("tenant_{tenant_id}")));
+
+let mut buffer = String::new();
+
+# #[cfg(feature = "mysql")]
+assert_eq!(
+    "LEFT JOIN `tenant_42`.`component_type` AS `component_type` ON `component_type`.`id` = `component`.`component_type_id`",
+    SqlJoin::format_mysql_join_clauses_with(&joins, qualify, &mut buffer)
+);
+# }
+```
+
+The schema name is split by `.`, so `catalog.schema` is quoted as `[catalog].[schema]` for Microsoft SQL Server.
+
+## Embedding in Larger Queries
+
+If a larger query already uses the names of the joined tables, `SqlJoinsOps::prefix_aliases` aliases every joined table with a prefix, e.g. `__ob_component_type`, and renames the references in the `ORDER BY` components. The base table can be renamed as well, or kept as it is with `None`.
+
+```rust
+use rdb_pagination::{prelude::*, SqlJoin, SqlJoinsOps};
+
+# #[cfg(feature = "derive")]
+# {
+#[derive(Debug, Clone, Default, OrderByOptions)]
+#[orderByOptions(name = component, join((component, component_type_id), (component_type, id)))]
+pub struct ComponentOrderBy {
+    #[orderByOptions((component_type, order))]
+    pub component_type_order: OrderMethod,
+}
+
+let order_by = ComponentOrderBy { component_type_order: OrderMethod::from(1) };
+
+let (mut joins, mut order_by_components) = order_by.to_sql();
+
+joins.prefix_aliases("__ob_", None, &mut order_by_components);
+
+let mut buffer = String::new();
+
+# #[cfg(feature = "mysql")]
+assert_eq!(
+    "LEFT JOIN `component_type` AS `__ob_component_type` ON `__ob_component_type`.`id` = `component`.`component_type_id`",
+    SqlJoin::format_mysql_join_clauses(&joins, &mut buffer)
+);
+# }
+```
+
+Alternatively, `SqlJoinsOps::merge_joins` merges the joins into the joins of the larger query. A join which is equivalent to an existing one is reused, even if the existing one has another alias, and a join whose alias is taken by a different join results in an error.
+
 ## Serde Support
 
 Enable the `serde` feature and add `serde` as a direct dependency with its `derive` feature to serialize and deserialize ordering options.
 
 ```toml
 [dependencies]
-rdb-pagination = { version = "0.3", features = ["serde"] }
+rdb-pagination = { version = "0.4", features = ["serde"] }
 serde = { version = "1", features = ["derive"] }
 serde_json = "1"
 ```
 
-`Pagination` implements serialization and validated deserialization directly, while `PaginationOptions<T>` implements each serde trait only when `T` implements the same trait.
+`Pagination` implements serialization and validated deserialization directly, while `PaginationOptions<T>`, `OffsetPaginationOptions<T>` and `AnyPaginationOptions<T>` implement each serde trait only when `T` implements the same trait.
 
 Application-defined ordering structs must derive `serde::Serialize` and `serde::Deserialize`, while each `OrderMethod` is represented by its inner integer value.
 
@@ -157,6 +565,179 @@ pub struct UserOrderBy {
 }
 # }
 ```
+
+## async-graphql Support
+
+Enable the `async-graphql` feature and add `async-graphql` as a direct dependency to derive `async_graphql::InputObject` for ordering options. Each `OrderMethod` is represented as an `Int`, and `OrderType` is a GraphQL enum.
+
+```toml
+[dependencies]
+rdb-pagination = { version = "0.4", features = ["async-graphql"] }
+async-graphql = "7"
+```
+
+```rust
+use educe::Educe;
+use rdb_pagination::prelude::*;
+
+# #[cfg(all(feature = "derive", feature = "async-graphql"))]
+# {
+use rdb_pagination::{ConnectionFields, PageQuery};
+
+/// Options for ordering users.
+#[derive(Debug, Clone, Educe, OrderByOptions, async_graphql::InputObject)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    /// Order users by their identifier.
+    #[graphql(default)]
+    #[orderByOptions((user, id), unique)]
+    pub id: OrderMethod,
+}
+
+let total_items = 50;
+
+// after: Some(19), before: None, first: Some(10), last: None
+let page_query = PageQuery::from_relay_arguments(Some(19), None, Some(10), None, total_items);
+
+assert_eq!(20, page_query.offset);
+
+let nodes = (20..30).map(|i| i.to_string());
+
+let connection: async_graphql::connection::Connection<usize, String, ConnectionFields> =
+    page_query.to_connection(total_items, nodes);
+# }
+```
+
+`Pagination::to_connection` creates a connection for page-based pagination, and `new_connection` accepts edges with any cursor type, such as keyset cursors. The connection provides `pageInfo { hasNextPage, hasPreviousPage, startCursor, endCursor }` and `totalCount`.
+
+## juniper Support
+
+Enable the `juniper` feature and add `juniper` as a direct dependency to derive `juniper::GraphQLInputObject` for ordering options. Each `OrderMethod` is a GraphQL scalar named `OrderMethod`, represented as an `Int`, and `PageInfo` is a Relay `PageInfo` object.
+
+```toml
+[dependencies]
+rdb-pagination = { version = "0.4", features = ["juniper"] }
+juniper = "0.17"
+```
+
+```rust
+use educe::Educe;
+use rdb_pagination::prelude::*;
+
+# #[cfg(all(feature = "derive", feature = "juniper"))]
+# {
+use rdb_pagination::{PageInfo, PageQuery, Pagination};
+
+/// Options for ordering users.
+#[derive(Debug, Clone, Educe, OrderByOptions, juniper::GraphQLInputObject)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    /// Order users by their identifier.
+    #[graphql(default)]
+    #[orderByOptions((user, id), unique)]
+    pub id: OrderMethod,
+}
+
+let pagination = Pagination::new().items_per_page(10).total_items(50).page(2);
+
+let page_info: PageInfo = pagination.to_page_info();
+
+assert!(page_info.has_previous_page);
+assert_eq!(Some("10"), page_info.start_cursor.as_deref());
+
+// after: Some(19), before: None, first: Some(10), last: None
+let page_query = PageQuery::from_relay_arguments(Some(19), None, Some(10), None, 50);
+
+let page_info = page_query.to_page_info(50, 10);
+
+assert_eq!(Some("29"), page_info.end_cursor.as_deref());
+# }
+```
+
+## JSON:API Support
+
+Enable the `json-api` feature to parse the `page[number]`, `page[size]`, `page[offset]`, `page[limit]`, `page[cursor]` and `sort` query parameters of JSON:API into `AnyPaginationOptions`, and to create the `links` and `meta` members from `Pagination`. Invalid parameters are reported as JSON:API error objects with `source.parameter`.
+
+```rust
+use educe::Educe;
+use rdb_pagination::prelude::*;
+
+# #[cfg(all(feature = "derive", feature = "json-api"))]
+# {
+use rdb_pagination::{AnyPaginationOptions, Pagination};
+
+#[derive(Debug, Clone, Educe, OrderByOptions)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, id), unique)]
+    pub id:   OrderMethod,
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod,
+}
+
+// GET /users?page[number]=2&page[size]=20&sort=-name,id
+let options = AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters([
+    ("page[number]", "2"),
+    ("page[size]", "20"),
+    ("sort", "-name,id"),
+])
+.unwrap();
+
+assert_eq!(OrderMethod::from(-1), options.get_order_by().name);
+
+let pagination = Pagination::new().items_per_page(20).total_items(50).page(2);
+
+let links = pagination.to_json_api_links("/users?sort=-name,id");
+let meta = pagination.to_json_api_meta();
+
+assert_eq!(Some("/users?sort=-name,id&page%5Bnumber%5D=3&page%5Bsize%5D=20"), links.next.as_deref());
+assert_eq!(3, meta.page.total_pages);
+# }
+```
+
+## OData Support
+
+Enable the `odata` feature to parse the `$top`, `$skip`, `$orderby` and `$count` query options of OData into `ODataQuery`, and to create the `@odata.count` and `@odata.nextLink` annotations. `$skip` does not have to be a multiple of `$top`; use `ODataQuery::page_query` to generate the `LIMIT` and `OFFSET` clauses.
+
+```rust
+use educe::Educe;
+use rdb_pagination::prelude::*;
+
+# #[cfg(all(feature = "derive", feature = "odata"))]
+# {
+use rdb_pagination::ODataQuery;
+
+#[derive(Debug, Clone, Educe, OrderByOptions)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[orderByOptions((user, id), unique)]
+    pub id:   OrderMethod,
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod,
+}
+
+// GET /Users?$top=20&$skip=15&$orderby=name desc,id&$count=true
+let query = ODataQuery::<UserOrderBy>::from_parameters([
+    ("$top", "20"),
+    ("$skip", "15"),
+    ("$orderby", "name desc,id"),
+    ("$count", "true"),
+])
+.unwrap();
+
+let page_query = query.page_query();
+
+assert_eq!(15, page_query.offset);
+
+let annotations = query.to_annotations("/Users?$orderby=name desc,id&$count=true", 50);
+
+assert_eq!(Some(50), annotations.count);
+# }
+```
 */
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -171,14 +752,23 @@ pub use rdb_pagination_derive::*;
 /// use rdb_pagination::prelude::*;
 /// ```
 pub mod prelude {
-    pub use rdb_pagination_core::{OrderByOptions, OrderMethod, OrderMethodValue};
+    pub use rdb_pagination_core::{
+        OrderByField, OrderByFields, OrderByOptions, OrderMethod, OrderMethodValue,
+    };
     #[cfg(feature = "derive")]
     pub use rdb_pagination_derive::OrderByOptions;
 
     #[doc(hidden)]
     pub mod rdb_pagination_prelude {
         pub use rdb_pagination_core::{
-            Name, NullStrategy, OrderBuilder, Relationship, SqlJoin, SqlOrderByComponent,
+            AccessDeniedError, AccessPolicy, Name, NullStrategy, OrderBuilder, OrderByExplanation,
+            OrderByField, OrderByFields, OrderMethod, OrderMethodError, OrderMethodLimits,
+            OrderMethodValue, OrderType, OutOfRangePolicy, Relationship, SqlJoin,
+            SqlOrderByComponent, TableColumn,
+        };
+        #[cfg(feature = "serde")]
+        pub use rdb_pagination_core::{
+            deserialize_order_by_field, serde, serialize_order_by_field,
         };
     }
 }
@@ -0,0 +1,169 @@
+#![cfg(all(feature = "derive", feature = "async-graphql"))]
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, connection::Connection};
+use educe::Educe;
+use rdb_pagination::{ConnectionFields, OrderType, PageQuery, Pagination, prelude::*};
+use serde_json::json;
+
+/// Options for ordering users.
+#[derive(Debug, Clone, Educe, OrderByOptions, async_graphql::InputObject)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    /// Order users by their identifier.
+    #[graphql(default)]
+    #[orderByOptions((user, id), unique)]
+    pub id:   OrderMethod,
+    /// Order users by their display name.
+    #[graphql(default)]
+    #[orderByOptions((user, name), unique)]
+    pub name: OrderMethod,
+}
+
+const USERS: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn users(
+        &self,
+        order_by: UserOrderBy,
+        after: Option<usize>,
+        before: Option<usize>,
+        first: Option<usize>,
+        last: Option<usize>,
+    ) -> Connection<usize, String, ConnectionFields> {
+        let (_, order_by_components) = order_by.to_sql();
+
+        let mut users = USERS.to_vec();
+
+        if matches!(order_by_components.first(), Some(c) if c.order_type == OrderType::Desc) {
+            users.reverse();
+        }
+
+        let page_query = PageQuery::from_relay_arguments(after, before, first, last, USERS.len());
+
+        let nodes = users
+            .into_iter()
+            .skip(page_query.offset as usize)
+            .take(page_query.limit.unwrap())
+            .map(String::from);
+
+        page_query.to_connection(USERS.len(), nodes)
+    }
+
+    async fn page(&self, page: usize) -> Connection<usize, String, ConnectionFields> {
+        let pagination = Pagination::new().items_per_page(2).total_items(USERS.len()).page(page);
+
+        pagination.to_connection(
+            USERS.iter().skip((pagination.get_page() - 1) * 2).take(2).map(|s| s.to_string()),
+        )
+    }
+
+    async fn order_type(&self, order_type: OrderType) -> OrderType {
+        order_type
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn connection() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let sdl = schema.sdl();
+
+    assert!(sdl.contains("input UserOrderBy"));
+    assert!(sdl.contains("id: Int! = 0"));
+    assert!(sdl.contains("enum OrderType"));
+
+    let response = block_on(schema.execute(
+        r#"{
+            users(orderBy: { id: -1 }, after: 0, first: 2) {
+                totalCount
+                pageInfo { hasPreviousPage hasNextPage startCursor endCursor }
+                edges { cursor node }
+            }
+            last: users(orderBy: {}, last: 2) {
+                pageInfo { hasPreviousPage hasNextPage startCursor endCursor }
+                nodes
+            }
+            page(page: 3) {
+                totalCount
+                pageInfo { hasPreviousPage hasNextPage startCursor endCursor }
+                nodes
+            }
+            orderType(orderType: DESC)
+        }"#,
+    ));
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    assert_eq!(
+        json!({
+            "users": {
+                "totalCount": 5,
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": true,
+                    "startCursor": "1",
+                    "endCursor": "2",
+                },
+                "edges": [
+                    { "cursor": "1", "node": "d" },
+                    { "cursor": "2", "node": "c" },
+                ],
+            },
+            "last": {
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                    "startCursor": "3",
+                    "endCursor": "4",
+                },
+                "nodes": ["d", "e"],
+            },
+            "page": {
+                "totalCount": 5,
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                    "startCursor": "4",
+                    "endCursor": "4",
+                },
+                "nodes": ["e"],
+            },
+            "orderType": "DESC",
+        }),
+        response.data.into_json().unwrap()
+    );
+}
+
+#[test]
+fn connection_max_offset() {
+    let page_query = PageQuery {
+        reversed: false, limit: Some(2), offset: u64::MAX
+    };
+
+    let connection = page_query.to_connection(5, ["a", "b"]);
+
+    assert!(connection.has_previous_page);
+    assert!(!connection.has_next_page);
+    assert_eq!([usize::MAX, usize::MAX], [connection.edges[0].cursor, connection.edges[1].cursor]);
+}
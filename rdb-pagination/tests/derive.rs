@@ -4,7 +4,10 @@
 ))]
 
 use educe::Educe;
-use rdb_pagination::{PaginationOptions, SqlJoin, SqlOrderByComponent, prelude::*};
+use rdb_pagination::{
+    AccessDeniedError, AccessPolicy, Name, OrderMethodError, OrderType, OutOfRangePolicy,
+    PaginationOptions, SqlJoin, SqlOrderByComponent, prelude::*,
+};
 
 #[test]
 fn component() {
@@ -134,3 +137,829 @@ fn component() {
         SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
     );
 }
+
+#[test]
+fn tiebreaker() {
+    #[derive(Debug, Clone, Educe, OrderByOptions)]
+    #[educe(Default)]
+    #[orderByOptions(name = component, tiebreaker = (component, id))]
+    #[orderByOptions(join((component, component_type_id), (component_type, id)))]
+    pub struct ComponentOrderBy {
+        #[educe(Default = 101)]
+        #[orderByOptions(("component_type", "order"))]
+        pub component_type_order: OrderMethod,
+        #[orderByOptions(("component", "name"), unique)]
+        pub name:                 OrderMethod,
+    }
+
+    let mut buffer = String::new();
+
+    let (_, order_by_components) = ComponentOrderBy::default().to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `component_type`.`order` ASC, `component`.`id` ASC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `component_type`.`order` ASC, `component`.`id` ASC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [component_type].[order] ASC, [component].[id] ASC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    buffer.clear();
+
+    let (_, order_by_components) = ComponentOrderBy {
+        name: OrderMethod::from(-1),
+        ..ComponentOrderBy::default()
+    }
+    .to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `component`.`name` DESC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `component`.`name` DESC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [component].[name] DESC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    buffer.clear();
+
+    // no warning is emitted for this non-deterministic ordering
+    #[derive(Debug, Clone, Default, OrderByOptions)]
+    #[orderByOptions(name = component, allow_non_unique)]
+    pub struct NameOrderBy {
+        #[orderByOptions(("component", "name"))]
+        pub name: OrderMethod,
+    }
+
+    let (_, order_by_components) = NameOrderBy {
+        name: OrderMethod::from(1)
+    }
+    .to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `component`.`name` ASC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `component`.`name` ASC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [component].[name] ASC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+}
+
+#[test]
+fn out_of_range() {
+    #[derive(Debug, Clone, Default, OrderByOptions)]
+    #[orderByOptions(name = component, out_of_range = error)]
+    pub struct ComponentOrderBy {
+        #[orderByOptions(("component", "name"), unique)]
+        pub name: OrderMethod,
+    }
+
+    assert_eq!(OutOfRangePolicy::Error, ComponentOrderBy::OUT_OF_RANGE_POLICY);
+
+    let options = PaginationOptions::<ComponentOrderBy>::default().page(4).items_per_page(20);
+
+    assert_eq!(4, options.to_pagination(50).get_page());
+    assert!(options.try_to_pagination(50).is_err());
+
+    #[derive(Debug, Clone, Default, OrderByOptions)]
+    #[orderByOptions(name = component, out_of_range = empty)]
+    pub struct EmptyOrderBy {}
+
+    assert_eq!(OutOfRangePolicy::Empty, EmptyOrderBy::OUT_OF_RANGE_POLICY);
+}
+
+#[test]
+fn order_by_fields() {
+    #[derive(Debug, Clone, Eq, PartialEq, Educe, OrderByOptions)]
+    #[educe(Default)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        /// Order users by their identifier.
+        #[educe(Default = 1)]
+        #[orderByOptions((user, id), unique)]
+        pub id:         OrderMethod,
+        #[orderByOptions((user, name))]
+        pub name:       OrderMethod,
+        #[orderByOptions((user, created_at))]
+        pub created_at: OrderMethod,
+    }
+
+    assert_eq!(
+        &[UserOrderByField::Id, UserOrderByField::Name, UserOrderByField::CreatedAt],
+        UserOrderByField::FIELDS
+    );
+    assert_eq!("created_at", UserOrderByField::CreatedAt.as_str());
+    assert_eq!(Some(UserOrderByField::Name), UserOrderByField::from_name("name"));
+    assert_eq!(None, UserOrderByField::from_name("Name"));
+
+    let order_by = UserOrderBy::from(vec![
+        (UserOrderByField::CreatedAt, OrderType::Desc),
+        (UserOrderByField::Name, OrderType::Asc),
+        (UserOrderByField::CreatedAt, OrderType::Asc),
+    ]);
+
+    assert_eq!(
+        UserOrderBy {
+            id:         OrderMethod::from(0),
+            name:       OrderMethod::from(2),
+            created_at: OrderMethod::from(-1),
+        },
+        order_by
+    );
+
+    assert_eq!(
+        vec![
+            (UserOrderByField::CreatedAt, OrderType::Desc),
+            (UserOrderByField::Name, OrderType::Asc)
+        ],
+        Vec::from(order_by.clone())
+    );
+
+    let mut buffer = String::new();
+
+    let (_, order_by_components) = order_by.to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `user`.`created_at` DESC, `user`.`name` ASC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `user`.`created_at` DESC, `user`.`name` ASC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [user].[created_at] DESC, [user].[name] ASC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    assert!(UserOrderBy::from_fields(&[]).to_fields().is_empty());
+    assert_eq!(vec![(UserOrderByField::Id, OrderType::Asc)], UserOrderBy::default().to_fields());
+}
+
+#[test]
+fn carrier_types() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user, tiebreaker = (user, id))]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, name))]
+        pub name:       Option<OrderMethod>,
+        #[orderByOptions((user, created_at), priority = 2)]
+        pub created_at: Option<OrderType>,
+        #[orderByOptions((user, email))]
+        pub email:      OrderMethod,
+    }
+
+    let mut buffer = String::new();
+
+    let order_by = UserOrderBy {
+        name:       Some(OrderMethod::from(3)),
+        created_at: Some(OrderType::Desc),
+        email:      OrderMethod::from(1),
+    };
+
+    let (_, order_by_components) = order_by.to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `user`.`email` ASC, `user`.`created_at` DESC, `user`.`name` ASC, `user`.`id` ASC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `user`.`email` ASC, `user`.`created_at` DESC, `user`.`name` ASC, `user`.`id` ASC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [user].[email] ASC, [user].[created_at] DESC, [user].[name] ASC, [user].[id] ASC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    assert_eq!(
+        vec![
+            (UserOrderByField::Email, OrderType::Asc),
+            (UserOrderByField::CreatedAt, OrderType::Desc),
+            (UserOrderByField::Name, OrderType::Asc),
+        ],
+        order_by.to_fields()
+    );
+
+    let order_by = UserOrderBy::from_fields(&[
+        (UserOrderByField::CreatedAt, OrderType::Asc),
+        (UserOrderByField::Name, OrderType::Desc),
+    ]);
+
+    assert_eq!(
+        UserOrderBy {
+            name:       Some(OrderMethod::from(-1)),
+            created_at: Some(OrderType::Asc),
+            email:      OrderMethod::from(0),
+        },
+        order_by
+    );
+
+    let (_, order_by_components) = UserOrderBy::default().to_sql();
+
+    assert_eq!(1, order_by_components.len());
+
+    #[derive(Debug, Clone, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    pub struct DirectionOrderBy {
+        #[orderByOptions((user, id), unique, priority = 300)]
+        pub id: Option<OrderType>,
+    }
+
+    let (_, order_by_components) = DirectionOrderBy {
+        id: Some(OrderType::Desc)
+    }
+    .to_sql();
+
+    assert_eq!(1, order_by_components.len());
+}
+
+#[test]
+fn carrier_types_from_fields() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user, tiebreaker = (user, id))]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, name), priority = 1)]
+        pub name:  Option<OrderType>,
+        #[orderByOptions((user, id), unique)]
+        pub id:    OrderMethod,
+        #[orderByOptions((user, email))]
+        pub email: Option<OrderMethod>,
+    }
+
+    let mut buffer = String::new();
+
+    let order_by = UserOrderBy::from_fields(&[
+        (UserOrderByField::Id, OrderType::Desc),
+        (UserOrderByField::Name, OrderType::Asc),
+        (UserOrderByField::Email, OrderType::Asc),
+    ]);
+
+    assert_eq!(
+        UserOrderBy {
+            name:  Some(OrderType::Asc),
+            id:    OrderMethod::from(-2),
+            email: Some(OrderMethod::from(3)),
+        },
+        order_by
+    );
+    assert_eq!(Ok(()), order_by.validate());
+
+    let (_, order_by_components) = order_by.to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `user`.`name` ASC, `user`.`id` DESC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `user`.`name` ASC, `user`.`id` DESC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [user].[name] ASC, [user].[id] DESC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    let fields = vec![
+        (UserOrderByField::Name, OrderType::Asc),
+        (UserOrderByField::Id, OrderType::Desc),
+        (UserOrderByField::Email, OrderType::Asc),
+    ];
+
+    assert_eq!(fields, order_by.to_fields());
+    assert_eq!(order_by, UserOrderBy::from_fields(&fields));
+
+    let order_by = UserOrderBy::from_fields(&[(UserOrderByField::Id, OrderType::Asc)]);
+
+    assert_eq!(
+        UserOrderBy {
+            name: None, id: OrderMethod::from(2), email: None
+        },
+        order_by
+    );
+    assert_eq!(vec![(UserOrderByField::Id, OrderType::Asc)], order_by.to_fields());
+}
+
+#[test]
+fn fixed() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    #[orderByOptions(column((user, id), unique, fixed = -999))]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, name))]
+        pub name:    OrderMethod<i16>,
+        #[orderByOptions((user, deleted), fixed = 1)]
+        pub deleted: (),
+    }
+
+    let mut buffer = String::new();
+
+    let (_, order_by_components) = UserOrderBy {
+        name: OrderMethod::from(2),
+        ..UserOrderBy::default()
+    }
+    .to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `user`.`deleted` ASC, `user`.`name` ASC, `user`.`id` DESC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `user`.`deleted` ASC, `user`.`name` ASC, `user`.`id` DESC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [user].[deleted] ASC, [user].[name] ASC, [user].[id] DESC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    assert_eq!(&[UserOrderByField::Name], UserOrderByField::FIELDS);
+    assert!(UserOrderBy::default().to_fields().is_empty());
+
+    let (_, order_by_components) = UserOrderBy::from_fields(&[]).to_sql();
+
+    assert_eq!(2, order_by_components.len());
+
+    // the value type is widened from `i8` to `i16` for `-999`
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    pub struct NarrowOrderBy {
+        #[orderByOptions((user, name))]
+        pub name: OrderMethod,
+        #[orderByOptions((user, id), unique, fixed = -999)]
+        pub id:   (),
+    }
+
+    let (_, order_by_components) = NarrowOrderBy {
+        name: OrderMethod::from(-1),
+        ..NarrowOrderBy::default()
+    }
+    .to_sql();
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `user`.`name` DESC, `user`.`id` DESC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `user`.`name` DESC, `user`.`id` DESC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [user].[name] DESC, [user].[id] DESC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+}
+
+#[test]
+fn presets() {
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = product, tiebreaker = (product, id))]
+    #[orderByOptions(join((product, category_id), (category, id)))]
+    pub enum ProductOrderBy {
+        #[default]
+        #[preset((product, created_at) desc, (product, id) unique desc)]
+        Newest,
+        #[preset((product, price) nulls_last, (category, name))]
+        PriceLowToHigh,
+        #[preset()]
+        Unordered,
+    }
+
+    let mut buffer = String::new();
+
+    let (joins, order_by_components) = ProductOrderBy::default().to_sql();
+
+    assert!(joins.is_empty());
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `product`.`created_at` DESC, `product`.`id` DESC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `product`.`created_at` DESC, `product`.`id` DESC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY [product].[created_at] DESC, [product].[id] DESC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    buffer.clear();
+
+    let (joins, order_by_components) = ProductOrderBy::PriceLowToHigh.to_sql();
+
+    assert_eq!(1, joins.len());
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        "ORDER BY `product`.`price` IS NULL, `product`.`price` ASC, `category`.`name` ASC, \
+         `product`.`id` ASC",
+        SqlOrderByComponent::format_mysql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        "ORDER BY `product`.`price` IS NULL, `product`.`price` ASC, `category`.`name` ASC, \
+         `product`.`id` ASC",
+        SqlOrderByComponent::format_sqlite_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    assert_eq!(
+        "ORDER BY CASE WHEN [product].[price] IS NULL THEN 1 ELSE 0 END, [product].[price] ASC, \
+         [category].[name] ASC, [product].[id] ASC",
+        SqlOrderByComponent::format_mssql_order_by_components(&order_by_components, &mut buffer)
+    );
+
+    let (_, order_by_components) = ProductOrderBy::Unordered.to_sql();
+
+    assert_eq!(1, order_by_components.len());
+}
+
+#[test]
+fn value_types() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:   OrderMethod<i16>,
+        #[orderByOptions((user, name))]
+        pub name: Option<OrderMethod>,
+    }
+
+    let order_by = UserOrderBy {
+        id: OrderMethod::from(300), name: Some(OrderMethod::from(-1))
+    };
+
+    let (_, order_by_components) = order_by.to_sql();
+
+    assert_eq!(2, order_by_components.len());
+    assert_eq!(OrderType::Desc, order_by_components[0].order_type);
+    assert_eq!(
+        vec![(UserOrderByField::Name, OrderType::Desc), (UserOrderByField::Id, OrderType::Asc)],
+        order_by.to_fields()
+    );
+
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    pub struct GenericOrderBy<T: OrderMethodValue> {
+        #[orderByOptions((user, id), unique)]
+        pub id:   OrderMethod<T>,
+        #[orderByOptions((user, name))]
+        pub name: OrderMethod<T>,
+    }
+
+    let order_by = GenericOrderBy::<i16> {
+        id:   OrderMethod::from(2),
+        name: OrderMethod::from(-1),
+    };
+
+    let (_, order_by_components) = order_by.to_sql();
+
+    assert_eq!(2, order_by_components.len());
+    assert_eq!(
+        vec![
+            (GenericOrderByField::Name, OrderType::Desc),
+            (GenericOrderByField::Id, OrderType::Asc)
+        ],
+        Vec::from(order_by.clone())
+    );
+    assert_eq!(order_by, GenericOrderBy::from_fields(&order_by.to_fields()));
+}
+
+#[test]
+fn min_values() {
+    assert_eq!(i32::MAX, OrderMethodValue::abs(&i32::MIN));
+    assert_eq!(i64::MAX, OrderMethodValue::abs(&i64::MIN));
+
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:   OrderMethod<i64>,
+        #[orderByOptions((user, name))]
+        pub name: OrderMethod<i32>,
+        #[orderByOptions((user, age))]
+        pub age:  OrderMethod,
+    }
+
+    let order_by = UserOrderBy {
+        id:   OrderMethod::from(i64::MIN),
+        name: OrderMethod::from(i32::MIN),
+        age:  OrderMethod::from(i8::MIN),
+    };
+
+    let (_, order_by_components) = order_by.to_sql();
+
+    assert_eq!(3, order_by_components.len());
+    assert!(order_by_components.iter().all(|component| component.order_type == OrderType::Desc));
+    assert_eq!(
+        vec![
+            (UserOrderByField::Age, OrderType::Desc),
+            (UserOrderByField::Name, OrderType::Desc),
+            (UserOrderByField::Id, OrderType::Desc)
+        ],
+        order_by.to_fields()
+    );
+    assert_eq!(Ok(()), order_by.validate());
+}
+
+#[test]
+fn validate() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user, max_priority = 3, max_fields = 2)]
+    #[orderByOptions(column((user, deleted), fixed = 1))]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:    OrderMethod<i16>,
+        #[orderByOptions((user, name))]
+        pub name:  Option<OrderMethod>,
+        #[orderByOptions((user, email), priority = 3)]
+        pub email: Option<OrderType>,
+    }
+
+    assert_eq!(Ok(()), UserOrderBy::default().validate());
+    assert_eq!(
+        Ok(()),
+        UserOrderBy {
+            id:    OrderMethod::from(2),
+            name:  Some(OrderMethod::from(-1)),
+            email: None,
+        }
+        .validate()
+    );
+
+    assert_eq!(
+        Err(OrderMethodError::DuplicatePriority {
+            priority: 1, fields: ["id", "name"]
+        }),
+        UserOrderBy {
+            id:    OrderMethod::from(-1),
+            name:  Some(OrderMethod::from(1)),
+            email: None,
+        }
+        .validate()
+    );
+
+    assert_eq!(
+        Err(OrderMethodError::PriorityTooLarge {
+            field:        "name",
+            priority:     4,
+            max_priority: 3,
+        }),
+        UserOrderBy {
+            id:    OrderMethod::from(1),
+            name:  Some(OrderMethod::from(-4)),
+            email: None,
+        }
+        .validate()
+    );
+
+    let error = UserOrderBy {
+        id:    OrderMethod::from(2),
+        name:  Some(OrderMethod::from(1)),
+        email: Some(OrderType::Desc),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        OrderMethodError::TooManyFields {
+            fields: vec!["name", "id", "email"], max_fields: 2
+        },
+        error
+    );
+    assert_eq!(
+        "3 fields are used for ordering, but at most 2 are allowed: `name`, `id`, `email`",
+        error.to_string()
+    );
+}
+
+#[test]
+fn requires() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:     OrderMethod,
+        #[orderByOptions((user, salary), requires = "admin")]
+        pub salary: OrderMethod,
+        #[orderByOptions((user, score), requires = auditor, priority = 3)]
+        pub score:  Option<OrderType>,
+    }
+
+    assert_eq!(&[("salary", "admin"), ("score", "auditor")], UserOrderBy::REQUIRED_CAPABILITIES);
+
+    let order_by =
+        UserOrderBy {
+            id: OrderMethod::from(2), salary: OrderMethod::from(-1), score: None
+        };
+
+    let admin = AccessPolicy::reject(|capability: &str| capability == "admin");
+    let user = AccessPolicy::drop(|_: &str| false);
+
+    let (_, order_by_components) = order_by.to_sql_with(&admin).unwrap();
+
+    assert_eq!(2, order_by_components.len());
+    assert_eq!("salary", order_by_components[0].column_name.as_ref());
+
+    let (_, order_by_components) = order_by.to_sql_with(&user).unwrap();
+
+    assert_eq!(1, order_by_components.len());
+    assert_eq!("id", order_by_components[0].column_name.as_ref());
+
+    assert_eq!(
+        AccessDeniedError {
+            field: "salary", capability: "admin"
+        },
+        order_by.to_sql_with(&AccessPolicy::reject(|_: &str| false)).unwrap_err()
+    );
+
+    // Disabled options are not rejected.
+    assert!(UserOrderBy::default().to_sql_with(&AccessPolicy::reject(|_: &str| false)).is_ok());
+
+    let order_by = UserOrderBy {
+        score: Some(OrderType::Desc),
+        ..UserOrderBy::default()
+    };
+
+    assert_eq!(
+        AccessDeniedError {
+            field: "score", capability: "auditor"
+        },
+        order_by.to_sql_with(&admin).unwrap_err()
+    );
+
+    let order_by =
+        UserOrderBy {
+            id: OrderMethod::from(2), salary: OrderMethod::from(-1), score: None
+        };
+
+    let (_, partition_by, order_by_components) = order_by
+        .to_partitioned_sql_with(vec![(Name::Static("user"), Name::Static("team_id"))], &user)
+        .unwrap();
+
+    assert_eq!(1, partition_by.len());
+    assert_eq!(1, order_by_components.len());
+    assert_eq!("id", order_by_components[0].column_name.as_ref());
+
+    assert!(
+        order_by
+            .to_partitioned_sql_with(Vec::new(), &AccessPolicy::reject(|_: &str| false))
+            .is_err()
+    );
+
+    let explanation = order_by.explain_with(&user).unwrap();
+
+    assert_eq!(1, explanation.options.len());
+    assert_eq!(Some(Name::Static("id")), explanation.options[0].field_name);
+
+    assert_eq!(2, order_by.explain_with(&admin).unwrap().options.len());
+    assert!(order_by.explain_with(&AccessPolicy::reject(|_: &str| false)).is_err());
+}
+
+#[test]
+fn alias() {
+    #[derive(Debug, Clone, Eq, PartialEq, Default, OrderByOptions)]
+    #[orderByOptions(name = component, alias = c, tiebreaker = (component, id))]
+    #[orderByOptions(join((component, component_type_id), (component_type, id)))]
+    pub struct ComponentOrderBy {
+        #[orderByOptions((component, name))]
+        pub name:                 OrderMethod,
+        #[orderByOptions((component_type, order))]
+        pub component_type_order: OrderMethod,
+    }
+
+    let order_by = ComponentOrderBy {
+        name:                 OrderMethod::from(2),
+        component_type_order: OrderMethod::from(-1),
+    };
+
+    let (joins, order_by_components) = order_by.to_sql();
+
+    let mut buffer = String::new();
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            "LEFT JOIN `component_type` ON `component_type`.`id` = `c`.`component_type_id`",
+            SqlJoin::format_mysql_join_clauses(&joins, &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ORDER BY `component_type`.`order` DESC, `c`.`name` ASC, `c`.`id` ASC",
+            SqlOrderByComponent::format_mysql_order_by_components(
+                &order_by_components,
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            "LEFT JOIN `component_type` ON `component_type`.`id` = `c`.`component_type_id`",
+            SqlJoin::format_sqlite_join_clauses(&joins, &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ORDER BY `component_type`.`order` DESC, `c`.`name` ASC, `c`.`id` ASC",
+            SqlOrderByComponent::format_sqlite_order_by_components(
+                &order_by_components,
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+    }
+
+    #[cfg(any(feature = "mssql", feature = "mssql2008"))]
+    {
+        assert_eq!(
+            "LEFT JOIN [component_type] ON [component_type].[id] = [c].[component_type_id]",
+            SqlJoin::format_mssql_join_clauses(&joins, &mut buffer)
+        );
+
+        buffer.clear();
+
+        assert_eq!(
+            "ORDER BY [component_type].[order] DESC, [c].[name] ASC, [c].[id] ASC",
+            SqlOrderByComponent::format_mssql_order_by_components(
+                &order_by_components,
+                &mut buffer
+            )
+        );
+
+        buffer.clear();
+    }
+
+    let (_, partition_by, _) =
+        order_by.to_partitioned_sql(vec![(Name::Static("component"), Name::Static("group_id"))]);
+
+    assert_eq!("c", partition_by[0].0.as_ref());
+}
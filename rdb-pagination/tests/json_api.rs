@@ -0,0 +1,224 @@
+#![cfg(all(feature = "derive", feature = "json-api"))]
+
+use educe::Educe;
+use rdb_pagination::{AnyPaginationOptions, JsonApiErrors, Pagination, prelude::*};
+use serde_json::json;
+
+#[derive(Debug, Clone, Educe, OrderByOptions)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[educe(Default = 1)]
+    #[orderByOptions((user, id), unique)]
+    pub id:         OrderMethod,
+    #[orderByOptions((user, name))]
+    pub name:       OrderMethod,
+    #[orderByOptions((user, created_at))]
+    pub created_at: OrderMethod,
+}
+
+#[test]
+fn parameters() {
+    let AnyPaginationOptions::Page(options) =
+        AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters([
+            ("page[number]", "3"),
+            ("page[size]", "20"),
+            ("sort", "-created_at,name"),
+            ("filter[name]", "a"),
+        ])
+        .unwrap()
+    else {
+        panic!("expected page-based options");
+    };
+
+    assert_eq!(3, options.page);
+    assert_eq!(20, options.items_per_page);
+    assert_eq!(OrderMethod::from(0), options.order_by.id);
+    assert_eq!(OrderMethod::from(2), options.order_by.name);
+    assert_eq!(OrderMethod::from(-1), options.order_by.created_at);
+
+    let AnyPaginationOptions::Offset(options) =
+        AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters([
+            ("page[offset]", "35"),
+            ("page[limit]", "20"),
+        ])
+        .unwrap()
+    else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(35, options.offset);
+    assert_eq!(Some(20), options.limit);
+    assert_eq!(OrderMethod::from(1), options.order_by.id);
+
+    let AnyPaginationOptions::Offset(options) =
+        AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters([
+            ("page[cursor]", "20"),
+            ("page[limit]", "10"),
+        ])
+        .unwrap()
+    else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(20, options.offset);
+    assert_eq!(Some(10), options.limit);
+
+    let AnyPaginationOptions::Offset(options) =
+        AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters([("page[limit]", "10")])
+            .unwrap()
+    else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(0, options.offset);
+    assert_eq!(Some(10), options.limit);
+}
+
+#[test]
+fn errors() {
+    let errors = AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters([
+        ("page[size]", "-1"),
+        ("sort", "-email,name"),
+    ])
+    .unwrap_err();
+
+    assert_eq!(
+        json!({
+            "errors": [
+                {
+                    "status": "400",
+                    "title": "Invalid Query Parameter",
+                    "detail": "`-1` is not a non-negative integer",
+                    "source": { "parameter": "page[size]" },
+                },
+                {
+                    "status": "400",
+                    "title": "Invalid Query Parameter",
+                    "detail": "`email` is not a sortable field",
+                    "source": { "parameter": "sort" },
+                },
+            ],
+        }),
+        serde_json::to_value(&errors).unwrap()
+    );
+
+    let parameters = |parameters: &[(&str, &str)]| -> Vec<String> {
+        let errors: JsonApiErrors = AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters(
+            parameters.iter().copied(),
+        )
+        .unwrap_err();
+
+        errors.errors.into_iter().map(|error| error.source.parameter).collect()
+    };
+
+    assert_eq!(vec!["page[cursor]"], parameters(&[("page[cursor]", "a")]));
+    assert_eq!(vec!["page[number]"], parameters(&[("page[number]", "2"), ("page[limit]", "20")]));
+    assert_eq!(vec!["page[offset]"], parameters(&[("page[offset]", "9223372036854775808")]));
+}
+
+#[test]
+fn validate() {
+    #[derive(Debug, Clone, Default, OrderByOptions)]
+    #[orderByOptions(name = user, max_fields = 1)]
+    pub struct LimitedOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:   OrderMethod,
+        #[orderByOptions((user, name))]
+        pub name: OrderMethod,
+    }
+
+    assert!(
+        AnyPaginationOptions::<LimitedOrderBy>::from_json_api_parameters([("sort", "-id")]).is_ok()
+    );
+
+    let errors =
+        AnyPaginationOptions::<LimitedOrderBy>::from_json_api_parameters([("sort", "name,-id")])
+            .unwrap_err();
+
+    assert_eq!(
+        json!({
+            "errors": [
+                {
+                    "status": "400",
+                    "title": "Invalid Query Parameter",
+                    "detail": "2 fields are used for ordering, but at most 1 are allowed: `name`, `id`",
+                    "source": { "parameter": "sort" },
+                },
+            ],
+        }),
+        serde_json::to_value(&errors).unwrap()
+    );
+}
+
+#[test]
+fn links_and_meta() {
+    let pagination = Pagination::new().items_per_page(20).total_items(50).page(2);
+
+    assert_eq!(
+        json!({
+            "self": "/users?sort=-name&page%5Bnumber%5D=2&page%5Bsize%5D=20",
+            "first": "/users?sort=-name&page%5Bnumber%5D=1&page%5Bsize%5D=20",
+            "prev": "/users?sort=-name&page%5Bnumber%5D=1&page%5Bsize%5D=20",
+            "next": "/users?sort=-name&page%5Bnumber%5D=3&page%5Bsize%5D=20",
+            "last": "/users?sort=-name&page%5Bnumber%5D=3&page%5Bsize%5D=20",
+        }),
+        serde_json::to_value(pagination.to_json_api_links("/users?sort=-name")).unwrap()
+    );
+
+    assert_eq!(
+        json!({
+            "page": {
+                "number": 2,
+                "size": 20,
+                "totalPages": 3,
+                "totalItems": 50,
+            },
+        }),
+        serde_json::to_value(pagination.to_json_api_meta()).unwrap()
+    );
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).at_offset(25);
+
+    assert_eq!(
+        json!({
+            "self": "/users?page%5Boffset%5D=25&page%5Blimit%5D=20",
+            "first": "/users?page%5Boffset%5D=0&page%5Blimit%5D=20",
+            "prev": "/users?page%5Boffset%5D=5&page%5Blimit%5D=20",
+            "next": "/users?page%5Boffset%5D=45&page%5Blimit%5D=20",
+            "last": "/users?page%5Boffset%5D=40&page%5Blimit%5D=20",
+        }),
+        serde_json::to_value(pagination.to_json_api_links("/users")).unwrap()
+    );
+
+    assert_eq!(Some(25), pagination.to_json_api_meta().page.offset);
+
+    // unlimited
+    let pagination = Pagination::new().total_items(50).at_offset(25);
+    let links = pagination.to_json_api_links("/users");
+
+    assert_eq!("/users?page%5Boffset%5D=25", links.self_link);
+    assert_eq!(Some("/users?page%5Boffset%5D=0"), links.prev.as_deref());
+    assert_eq!(None, links.next);
+
+    let Ok(AnyPaginationOptions::Offset(options)) =
+        AnyPaginationOptions::<UserOrderBy>::from_json_api_parameters(
+            links.self_link.split_once('?').unwrap().1.split('&').map(|parameter| {
+                let (key, value) = parameter.split_once('=').unwrap();
+
+                (key.replace("%5B", "[").replace("%5D", "]"), value.to_string())
+            }),
+        )
+    else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(25, options.offset);
+    assert_eq!(None, options.limit);
+
+    let links = Pagination::new().to_json_api_links("/users");
+
+    assert_eq!("/users?page%5Bnumber%5D=1&page%5Bsize%5D=0", links.last);
+    assert_eq!(None, links.prev);
+    assert_eq!(None, links.next);
+}
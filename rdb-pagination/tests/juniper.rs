@@ -0,0 +1,111 @@
+#![cfg(all(feature = "derive", feature = "juniper"))]
+
+use educe::Educe;
+use juniper::{
+    EmptyMutation, EmptySubscription, GraphQLInputObject, RootNode, Variables, graphql_object,
+    graphql_value,
+};
+use rdb_pagination::{PageInfo, PageQuery, Pagination, prelude::*};
+
+/// Options for ordering users.
+#[derive(Debug, Clone, Educe, OrderByOptions, GraphQLInputObject)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    /// Order users by their identifier.
+    #[graphql(default)]
+    #[orderByOptions((user, id), unique)]
+    pub id: OrderMethod,
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn order_method(order_by: UserOrderBy) -> OrderMethod {
+        order_by.id
+    }
+
+    fn page(page: i32) -> PageInfo {
+        Pagination::new().items_per_page(2).total_items(5).page(page as usize).to_page_info()
+    }
+}
+
+type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+
+#[test]
+fn page_info() {
+    let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+    let sdl = schema.as_sdl();
+
+    assert!(sdl.contains("input UserOrderBy"));
+    assert!(sdl.contains("id: OrderMethod! = 0"));
+    assert!(sdl.contains("scalar OrderMethod"));
+    assert!(sdl.contains("Order users by their identifier."));
+
+    let (data, errors) = juniper::execute_sync(
+        r#"{
+            orderMethod(orderBy: { id: -1 })
+            page(page: 3) { hasPreviousPage hasNextPage startCursor endCursor }
+        }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    )
+    .unwrap();
+
+    assert!(errors.is_empty(), "{errors:?}");
+
+    assert_eq!(
+        graphql_value!({
+            "orderMethod": -1,
+            "page": {
+                "hasPreviousPage": true,
+                "hasNextPage": false,
+                "startCursor": "4",
+                "endCursor": "4",
+            },
+        }),
+        data
+    );
+}
+
+#[test]
+fn invalid_order_method() {
+    let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+
+    let result = juniper::execute_sync(
+        r#"{ orderMethod(orderBy: { id: 200 }) }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn page_info_max_offset() {
+    let page_query = PageQuery {
+        reversed: false, limit: Some(2), offset: u64::MAX
+    };
+
+    let page_info = page_query.to_page_info(5, 2);
+
+    assert!(page_info.has_previous_page);
+    assert!(!page_info.has_next_page);
+    assert_eq!(None, page_info.start_cursor);
+    assert_eq!(None, page_info.end_cursor);
+
+    let page_info = PageQuery {
+        reversed: false, limit: Some(2), offset: 5
+    }
+    .to_page_info(5, 2);
+
+    assert!(!page_info.has_next_page);
+    assert_eq!(None, page_info.start_cursor);
+    assert_eq!(None, page_info.end_cursor);
+}
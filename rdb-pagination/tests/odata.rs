@@ -0,0 +1,195 @@
+#![cfg(all(feature = "derive", feature = "odata"))]
+
+use educe::Educe;
+use rdb_pagination::{AnyPaginationOptions, ODataQuery, PageQuery, Pagination, prelude::*};
+use serde_json::json;
+
+#[derive(Debug, Clone, Educe, OrderByOptions)]
+#[educe(Default)]
+#[orderByOptions(name = user)]
+pub struct UserOrderBy {
+    #[educe(Default = 1)]
+    #[orderByOptions((user, id), unique)]
+    pub id:   OrderMethod,
+    #[orderByOptions((user, name))]
+    pub name: OrderMethod,
+}
+
+#[test]
+fn query() {
+    let query = ODataQuery::<UserOrderBy>::from_parameters([
+        ("$top", "20"),
+        ("$skip", "30"),
+        ("$orderby", "name desc, id asc"),
+        ("$count", "true"),
+        ("$filter", "name eq 'a'"),
+    ])
+    .unwrap();
+
+    assert_eq!(Some(20), query.top);
+    assert_eq!(30, query.skip);
+    assert!(query.count);
+    assert_eq!(OrderMethod::from(-1), query.order_by.name);
+    assert_eq!(OrderMethod::from(2), query.order_by.id);
+
+    assert_eq!(
+        PageQuery {
+            reversed: false, limit: Some(20), offset: 30
+        },
+        query.page_query()
+    );
+
+    let AnyPaginationOptions::Offset(options) = query.to_pagination_options() else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(30, options.offset);
+    assert_eq!(Some(20), options.limit);
+    assert_eq!(OrderMethod::from(-1), options.order_by.name);
+
+    let query =
+        ODataQuery::<UserOrderBy>::from_parameters([("$top", "20"), ("$skip", "40")]).unwrap();
+
+    let AnyPaginationOptions::Page(options) = query.to_pagination_options() else {
+        panic!("expected page-based options");
+    };
+
+    assert_eq!(3, options.page);
+    assert_eq!(20, options.items_per_page);
+    assert_eq!(OrderMethod::from(1), options.order_by.id);
+
+    let AnyPaginationOptions::Offset(options) =
+        ODataQuery::<UserOrderBy>::from_parameters([("$top", "0"), ("$skip", "40")])
+            .unwrap()
+            .to_pagination_options()
+    else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(40, options.offset);
+    assert_eq!(Some(0), options.limit);
+
+    #[cfg(feature = "mysql")]
+    assert_eq!("LIMIT 0 OFFSET 40", options.to_mysql_limit_offset(&mut String::new()));
+
+    #[cfg(feature = "mssql")]
+    assert_eq!(
+        "OFFSET 9223372036854775807 ROWS",
+        options.to_mssql_limit_offset(&mut String::new())
+    );
+
+    // the page number `u64::MAX + 1` does not fit in `usize`
+    let query = ODataQuery {
+        top:      Some(1),
+        skip:     u64::MAX,
+        order_by: UserOrderBy::default(),
+        count:    false,
+    };
+
+    let AnyPaginationOptions::Offset(options) = query.to_pagination_options() else {
+        panic!("expected offset-based options");
+    };
+
+    assert_eq!(u64::MAX, options.offset);
+    assert_eq!(Some(1), options.limit);
+
+    let options = ODataQuery::<UserOrderBy>::from_parameters([("$orderby", "name")]).unwrap();
+
+    assert_eq!(OrderMethod::from(1), options.order_by.name);
+    assert_eq!(OrderMethod::from(0), options.order_by.id);
+}
+
+#[test]
+fn errors() {
+    let target = |parameters: &[(&str, &str)]| {
+        ODataQuery::<UserOrderBy>::from_parameters(parameters.iter().copied()).unwrap_err().target
+    };
+
+    assert_eq!("$top", target(&[("$top", "-1")]));
+    assert_eq!("$skip", target(&[("$skip", "a")]));
+    assert_eq!("$skip", target(&[("$skip", "9223372036854775808")]));
+    assert_eq!("$count", target(&[("$count", "1")]));
+    assert_eq!("$orderby", target(&[("$orderby", "email desc")]));
+    assert_eq!("$orderby", target(&[("$orderby", "name down")]));
+
+    let error = ODataQuery::<UserOrderBy>::from_parameters([("$orderby", "email")]).unwrap_err();
+
+    assert_eq!(
+        json!({
+            "code": "BadRequest",
+            "message": "`email` is not a sortable property",
+            "target": "$orderby",
+        }),
+        serde_json::to_value(error).unwrap()
+    );
+}
+
+#[test]
+fn validate() {
+    #[derive(Debug, Clone, Default, OrderByOptions)]
+    #[orderByOptions(name = user, max_fields = 1)]
+    pub struct LimitedOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:   OrderMethod,
+        #[orderByOptions((user, name))]
+        pub name: OrderMethod,
+    }
+
+    assert!(ODataQuery::<LimitedOrderBy>::from_parameters([("$orderby", "id desc")]).is_ok());
+
+    let error =
+        ODataQuery::<LimitedOrderBy>::from_parameters([("$orderby", "name, id desc")]).unwrap_err();
+
+    assert_eq!(
+        json!({
+            "code": "BadRequest",
+            "message": "2 fields are used for ordering, but at most 1 are allowed: `name`, `id`",
+            "target": "$orderby",
+        }),
+        serde_json::to_value(error).unwrap()
+    );
+}
+
+#[test]
+fn annotations() {
+    let query = ODataQuery::<UserOrderBy>::from_parameters([
+        ("$top", "20"),
+        ("$skip", "15"),
+        ("$count", "true"),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        json!({
+            "@odata.count": 50,
+            "@odata.nextLink": "/Users?$count=true&$top=20&$skip=35",
+        }),
+        serde_json::to_value(query.to_annotations("/Users?$count=true", 50)).unwrap()
+    );
+
+    assert_eq!(
+        json!({ "@odata.count": 35 }),
+        serde_json::to_value(query.to_annotations("/Users?$count=true", 35)).unwrap()
+    );
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).page(3);
+
+    assert_eq!(
+        json!({}),
+        serde_json::to_value(pagination.to_odata_annotations("/Users", false)).unwrap()
+    );
+
+    let pagination = pagination.at_offset(15);
+
+    assert_eq!(
+        json!({ "@odata.nextLink": "/Users?$top=20&$skip=35" }),
+        serde_json::to_value(pagination.to_odata_annotations("/Users", false)).unwrap()
+    );
+
+    let pagination = pagination.page(1);
+
+    assert_eq!(
+        json!({ "@odata.nextLink": "/Users?$top=20&$skip=20" }),
+        serde_json::to_value(pagination.to_odata_annotations("/Users", false)).unwrap()
+    );
+}
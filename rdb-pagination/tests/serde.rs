@@ -1,7 +1,10 @@
 #![cfg(all(feature = "derive", feature = "serde"))]
 
 use educe::Educe;
-use rdb_pagination::{Pagination, PaginationOptions, prelude::*};
+use rdb_pagination::{
+    AnyPaginationOptions, OffsetPaginationOptions, OptionNonZeroI16, OrderType, PageItem,
+    PageQuery, Pagination, PaginationOptions, prelude::*,
+};
 use serde_json::json;
 
 #[test]
@@ -55,6 +58,104 @@ fn pagination_options() {
     assert_eq!(OrderMethod::default(), options.order_by.id);
     assert_eq!(OrderMethod::default(), options.order_by.name);
 
+    let options = PaginationOptions::<UserOrderBy>::default().items_per_page(20).at_offset(35);
+
+    let value = serde_json::to_value(&options).unwrap();
+
+    assert_eq!(
+        json!({
+            "offset": 35,
+            "limit": 20,
+            "order_by": {
+                "id": 0,
+                "name": 0,
+            },
+        }),
+        value
+    );
+
+    let options: OffsetPaginationOptions<UserOrderBy> = serde_json::from_value(value).unwrap();
+
+    assert_eq!(35, options.offset);
+    assert_eq!(Some(20), options.limit);
+
+    let options: AnyPaginationOptions<UserOrderBy> =
+        serde_json::from_value(json!({ "offset": 10 })).unwrap();
+
+    assert!(matches!(
+        options,
+        AnyPaginationOptions::Offset(OffsetPaginationOptions {
+            offset: 10,
+            limit: None,
+            ..
+        })
+    ));
+
+    let options: AnyPaginationOptions<UserOrderBy> =
+        serde_json::from_value(json!({ "offset": 40, "items_per_page": 20 })).unwrap();
+
+    assert!(matches!(
+        options,
+        AnyPaginationOptions::Offset(OffsetPaginationOptions {
+            offset: 40,
+            limit: Some(20),
+            ..
+        })
+    ));
+
+    let options: AnyPaginationOptions<UserOrderBy> =
+        serde_json::from_value(json!({ "page": 2, "items_per_page": 20 })).unwrap();
+
+    assert!(matches!(
+        options,
+        AnyPaginationOptions::Page(PaginationOptions {
+            page: 2,
+            items_per_page: 20,
+            ..
+        })
+    ));
+
+    assert!(
+        serde_json::from_value::<AnyPaginationOptions<UserOrderBy>>(json!({
+            "page": 2,
+            "offset": 10,
+        }))
+        .is_err()
+    );
+    assert!(
+        serde_json::from_value::<AnyPaginationOptions<UserOrderBy>>(json!({
+            "items_per_page": 20,
+            "limit": 10,
+        }))
+        .is_err()
+    );
+    assert!(
+        serde_json::from_value::<OffsetPaginationOptions<UserOrderBy>>(
+            json!({ "offset": PageQuery::MAX_OFFSET })
+        )
+        .is_ok()
+    );
+    assert!(
+        serde_json::from_value::<OffsetPaginationOptions<UserOrderBy>>(
+            json!({ "offset": PageQuery::MAX_OFFSET + 1 })
+        )
+        .is_err()
+    );
+    assert!(
+        serde_json::from_value::<AnyPaginationOptions<UserOrderBy>>(json!({ "offset": u64::MAX }))
+            .is_err()
+    );
+
+    let error = serde_json::from_value::<PaginationOptions<UserOrderBy>>(json!({
+        "order_by": {
+            "id": -1,
+            "name": 1,
+        },
+    }))
+    .unwrap_err();
+
+    assert_eq!("`id` and `name` have the same priority 1", error.to_string());
+
     let order_method = OrderMethod::<i16>::from(-1024);
     let value = serde_json::to_value(order_method).unwrap();
 
@@ -102,4 +203,213 @@ fn pagination() {
         }))
         .is_err()
     );
+    assert!(
+        serde_json::from_value::<Pagination>(json!({
+            "page": 4,
+            "total_pages": 3,
+            "items_per_page": 20,
+            "total_items": 50,
+            "out_of_range_policy": "error",
+        }))
+        .is_err()
+    );
+
+    assert!(
+        serde_json::from_value::<Pagination>(json!({
+            "page": 4,
+            "total_pages": 3,
+            "items_per_page": 20,
+            "total_items": 50,
+            "out_of_range_policy": "clamp",
+        }))
+        .is_err()
+    );
+
+    let pagination: Pagination = serde_json::from_value(json!({
+        "page": 3,
+        "total_pages": 3,
+        "items_per_page": 20,
+        "total_items": 50,
+        "out_of_range_policy": "clamp",
+    }))
+    .unwrap();
+
+    assert_eq!(3, pagination.get_page());
+
+    let pagination: Pagination = serde_json::from_value(json!({
+        "page": 4,
+        "total_pages": 3,
+        "items_per_page": 20,
+        "total_items": 50,
+        "out_of_range_policy": "empty",
+    }))
+    .unwrap();
+
+    assert_eq!(4, pagination.get_page());
+    assert!(pagination.is_out_of_range());
+    assert_eq!(
+        json!({
+            "page": 4,
+            "total_pages": 3,
+            "items_per_page": 20,
+            "total_items": 50,
+            "out_of_range_policy": "empty",
+        }),
+        serde_json::to_value(&pagination).unwrap()
+    );
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).at_offset(35);
+
+    let value = serde_json::to_value(&pagination).unwrap();
+
+    assert_eq!(
+        json!({
+            "page": 2,
+            "total_pages": 3,
+            "items_per_page": 20,
+            "total_items": 50,
+            "offset": 35,
+        }),
+        value
+    );
+
+    let pagination: Pagination = serde_json::from_value(value).unwrap();
+
+    assert_eq!(35, pagination.get_offset());
+
+    assert!(
+        serde_json::from_value::<Pagination>(json!({
+            "page": 1,
+            "total_pages": 3,
+            "items_per_page": 20,
+            "total_items": 50,
+            "offset": 35,
+        }))
+        .is_err()
+    );
+
+    let pagination = Pagination::new().items_per_page(20).total_items(50).at_offset(1000);
+
+    let pagination: Pagination =
+        serde_json::from_value(serde_json::to_value(&pagination).unwrap()).unwrap();
+
+    assert_eq!(3, pagination.get_page());
+    assert_eq!(1000, pagination.get_offset());
+}
+
+#[test]
+fn order_by_fields() {
+    #[derive(Debug, Clone, Educe, OrderByOptions)]
+    #[educe(Default)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:         OrderMethod,
+        #[orderByOptions((user, created_at))]
+        pub created_at: OrderMethod,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct SortField {
+        field:     UserOrderByField,
+        direction: OrderType,
+    }
+
+    let sort: Vec<SortField> = serde_json::from_value(json!([
+        { "field": "created_at", "direction": "desc" },
+        { "field": "id", "direction": "ASC" },
+    ]))
+    .unwrap();
+
+    let order_by =
+        UserOrderBy::from_fields(&sort.iter().map(|s| (s.field, s.direction)).collect::<Vec<_>>());
+
+    assert_eq!(OrderMethod::from(2), order_by.id);
+    assert_eq!(OrderMethod::from(-1), order_by.created_at);
+
+    assert_eq!(
+        json!([["created_at", "desc"], ["id", "asc"]]),
+        serde_json::to_value(order_by.to_fields()).unwrap()
+    );
+
+    let error = serde_json::from_value::<UserOrderByField>(json!("name")).unwrap_err();
+
+    assert!(
+        error.to_string().contains(r#"unknown field `name`, expected one of ["id", "created_at"]"#)
+    );
+}
+
+#[test]
+fn pager() {
+    let pagination = Pagination::new().items_per_page(10).total_items(200).page(6);
+
+    assert_eq!(
+        json!([{ "page": 1 }, "ellipsis", { "page": 5 }, { "page": 6 }, { "page": 7 }, "ellipsis", { "page": 20 }]),
+        serde_json::to_value(pagination.page_window(1, 1)).unwrap()
+    );
+
+    assert_eq!(
+        vec![PageItem::Page(1), PageItem::Ellipsis],
+        serde_json::from_value::<Vec<PageItem>>(json!([{ "page": 1 }, "ellipsis"])).unwrap()
+    );
+
+    assert_eq!(
+        json!({ "start": 51, "end": 60 }),
+        serde_json::to_value(pagination.item_range()).unwrap()
+    );
+}
+
+#[test]
+fn presets() {
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Eq,
+        PartialEq,
+        Default,
+        OrderByOptions,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    #[serde(rename_all = "snake_case")]
+    #[orderByOptions(name = product)]
+    pub enum ProductOrderBy {
+        #[default]
+        #[preset((product, created_at) desc, (product, id) unique desc)]
+        Newest,
+        #[preset((product, price), (product, id) unique)]
+        PriceLowToHigh,
+    }
+
+    let options = PaginationOptions::default().order_by(ProductOrderBy::PriceLowToHigh);
+
+    let value = serde_json::to_value(&options).unwrap();
+
+    assert_eq!(
+        json!({
+            "page": 1,
+            "items_per_page": 0,
+            "order_by": "price_low_to_high",
+        }),
+        value
+    );
+
+    let options: PaginationOptions<ProductOrderBy> = serde_json::from_value(value).unwrap();
+
+    assert_eq!(ProductOrderBy::PriceLowToHigh, options.order_by);
+
+    assert!(serde_json::from_value::<ProductOrderBy>(json!("oldest")).is_err());
+}
+
+#[test]
+fn order_method_values() {
+    assert_eq!(json!(-2000), serde_json::to_value(OrderMethod(-2000i32)).unwrap());
+    assert_eq!(OrderMethod(3i64), serde_json::from_value(json!(3)).unwrap());
+
+    let disabled = OrderMethod(OptionNonZeroI16(None));
+
+    assert_eq!(json!(0), serde_json::to_value(disabled).unwrap());
+    assert_eq!(disabled, serde_json::from_value(json!(0)).unwrap());
+    assert_eq!(OrderMethod(OptionNonZeroI16::from(-2)), serde_json::from_value(json!(-2)).unwrap());
 }
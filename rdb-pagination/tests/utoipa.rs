@@ -1,7 +1,7 @@
 #![cfg(feature = "utoipa")]
 
 use educe::Educe;
-use rdb_pagination::prelude::*;
+use rdb_pagination::{RequiredCapabilities, prelude::*};
 use serde_json::Value;
 use utoipa::OpenApi;
 
@@ -72,3 +72,100 @@ fn order_by_options() {
         );
     }
 }
+
+#[test]
+fn presets() {
+    /// Sort modes of products.
+    #[derive(Debug, Clone, Default, OrderByOptions, utoipa::ToSchema)]
+    #[schema(rename_all = "snake_case")]
+    #[orderByOptions(name = product)]
+    pub enum ProductOrderBy {
+        #[default]
+        #[preset((product, created_at) desc, (product, id) unique desc)]
+        Newest,
+        #[preset((product, price), (product, id) unique)]
+        PriceLowToHigh,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(ProductOrderBy)))]
+    struct ApiDoc;
+
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schema = &document["components"]["schemas"]["ProductOrderBy"];
+
+    assert_eq!("string", schema["type"]);
+    assert_eq!(serde_json::json!(["newest", "price_low_to_high"]), schema["enum"]);
+    assert_eq!(2, ProductOrderBy::PriceLowToHigh.to_sql().1.len());
+}
+
+#[test]
+fn order_method_values() {
+    #[derive(Debug, Clone, Default, OrderByOptions, utoipa::ToSchema)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:   OrderMethod<i32>,
+        #[orderByOptions((user, name))]
+        pub name: OrderMethod<i64>,
+    }
+
+    #[derive(Debug, Clone, Default, OrderByOptions, utoipa::ToSchema)]
+    #[orderByOptions(name = user)]
+    pub struct ExplicitUserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id: OrderMethod<rdb_pagination::OptionNonZeroI16>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(UserOrderBy, ExplicitUserOrderBy)))]
+    struct ApiDoc;
+
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = document["components"]["schemas"].as_object().unwrap();
+
+    let properties = schemas["UserOrderBy"]["properties"]
+        .as_object()
+        .unwrap()
+        .values()
+        .chain(schemas["ExplicitUserOrderBy"]["properties"].as_object().unwrap().values());
+
+    for property in properties {
+        let schema = match property["$ref"].as_str() {
+            Some(reference) => &schemas[reference.rsplit('/').next().unwrap()],
+            None => property,
+        };
+        let integer_schema =
+            schema["oneOf"].as_array().and_then(|schemas| schemas.first()).unwrap_or(schema);
+
+        assert_eq!("integer", integer_schema["type"]);
+    }
+
+    assert!(UserOrderBy::default().to_sql().1.is_empty());
+    assert!(ExplicitUserOrderBy::default().to_sql().1.is_empty());
+}
+
+#[test]
+fn required_capabilities() {
+    #[derive(Debug, Clone, Default, OrderByOptions, utoipa::ToSchema)]
+    #[orderByOptions(name = user)]
+    pub struct UserOrderBy {
+        #[orderByOptions((user, id), unique)]
+        pub id:     OrderMethod,
+        #[orderByOptions((user, salary), requires = "admin")]
+        pub salary: OrderMethod,
+    }
+
+    const USER_CAPABILITIES: RequiredCapabilities<UserOrderBy> = RequiredCapabilities::new();
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(UserOrderBy)), modifiers(&USER_CAPABILITIES))]
+    struct ApiDoc;
+
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(
+        serde_json::json!({ "salary": "admin" }),
+        document["components"]["schemas"]["UserOrderBy"]["x-required-capabilities"]
+    );
+}